@@ -1,7 +1,11 @@
 //! Applicant data structures.
 
+use chrono::{Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use validator::ValidationError;
+
+use super::validation_error;
 
 /// Applicant data used for risk assessment
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +111,9 @@ pub struct CreditInfo {
     /// Credit score
     pub credit_score: u16,
     
+    /// Scale the credit score is reported in (FICO when absent)
+    pub credit_score_scale: Option<CreditScoreScale>,
+    
     /// Number of open accounts
     pub open_accounts: u8,
     
@@ -135,6 +142,75 @@ pub struct CreditInfo {
     pub collections: u8,
 }
 
+/// Credit score scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CreditScoreScale {
+    /// FICO (300-850)
+    Fico,
+    
+    /// VantageScore 3.0/4.0 (300-850)
+    VantageScore,
+    
+    /// Internal scale (0-1000)
+    Internal,
+}
+
+impl CreditScoreScale {
+    /// Returns the string representation of the scale
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreditScoreScale::Fico => "fico",
+            CreditScoreScale::VantageScore => "vantage_score",
+            CreditScoreScale::Internal => "internal",
+        }
+    }
+    
+    /// Parses a scale from its string representation
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "fico" => Some(CreditScoreScale::Fico),
+            "vantage_score" => Some(CreditScoreScale::VantageScore),
+            "internal" => Some(CreditScoreScale::Internal),
+            _ => None,
+        }
+    }
+    
+    /// Returns the valid score range of the scale
+    pub fn range(&self) -> (f64, f64) {
+        match self {
+            CreditScoreScale::Fico => (300.0, 850.0),
+            CreditScoreScale::VantageScore => (300.0, 850.0),
+            CreditScoreScale::Internal => (0.0, 1000.0),
+        }
+    }
+    
+    /// Linearly maps a score from this scale to another
+    pub fn convert(&self, score: f64, to: CreditScoreScale) -> f64 {
+        let (from_min, from_max) = self.range();
+        let (to_min, to_max) = to.range();
+        to_min + (score - from_min) / (from_max - from_min) * (to_max - to_min)
+    }
+}
+
+/// Record of a credit score conversion between scales
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditScoreConversion {
+    /// Scale the score was supplied in
+    pub from: CreditScoreScale,
+    
+    /// Scale the model expects
+    pub to: CreditScoreScale,
+    
+    /// Supplied score
+    pub original: f64,
+    
+    /// Converted score
+    pub converted: f64,
+    
+    /// True if the supplied score was outside its scale and had to be clamped
+    pub out_of_range: bool,
+}
+
 /// Employment information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmploymentInfo {
@@ -168,15 +244,47 @@ pub enum EmploymentStatus {
     Other,
 }
 
+impl CreditInfo {
+    /// Converts the credit score to the target scale.
+    /// Out-of-range scores are rejected when `strict`, otherwise clamped and flagged.
+    pub fn normalize_credit_score(&self, target: CreditScoreScale, strict: bool) -> Result<CreditScoreConversion, ValidationError> {
+        let from = self.credit_score_scale.unwrap_or(CreditScoreScale::Fico);
+        let (min, max) = from.range();
+        let original = self.credit_score as f64;
+        let out_of_range = original < min || original > max;
+        
+        if out_of_range && strict {
+            return Err(validation_error("credit_score_out_of_range", format!("Credit score {} is outside the {:?} range", original, from)));
+        }
+        
+        let converted = from.convert(original.clamp(min, max), target);
+        
+        Ok(CreditScoreConversion {
+            from,
+            to: target,
+            original,
+            converted,
+            out_of_range,
+        })
+    }
+}
+
 impl ApplicantData {
     /// Convert to model input format
     pub fn to_input_format(&self) -> HashMap<String, serde_json::Value> {
+        self.to_input_format_at(Utc::now().date_naive())
+    }
+    
+    /// Convert to model input format, computing the age as of the given date
+    pub fn to_input_format_at(&self, today: NaiveDate) -> HashMap<String, serde_json::Value> {
         let mut input = HashMap::new();
         
         // Flatten the structure for model input
         // Personal info
         input.insert("name".to_string(), serde_json::to_value(&self.personal_info.name).unwrap());
-        input.insert("age".to_string(), self.calculate_age());
+        if let Ok(age) = self.calculate_age_at(today) {
+            input.insert("age".to_string(), serde_json::to_value(age).unwrap());
+        }
         input.insert("address_years".to_string(), serde_json::to_value(&self.personal_info.years_at_address).unwrap());
         input.insert("dependents".to_string(), serde_json::to_value(&self.personal_info.dependents).unwrap());
         input.insert("postal_code".to_string(), serde_json::to_value(&self.personal_info.address.postal_code).unwrap());
@@ -200,4 +308,173 @@ impl ApplicantData {
         input.insert("credit_balance".to_string(), serde_json::to_value(&self.credit_info.total_current_balance).unwrap());
         input.insert("utilization".to_string(), serde_json::to_value(&self.credit_info.credit_utilization).unwrap());
         input.insert("public_records".to_string(), serde_json::to_value(&self.credit_info.public_records).unwrap());
-        input.insert
\ No newline at end of file
+        input.insert("collections".to_string(), serde_json::to_value(self.credit_info.collections).unwrap());
+        
+        // Employment info
+        input.insert("employment_status".to_string(), serde_json::to_value(self.employment_info.status).unwrap());
+        input.insert("job_title".to_string(), serde_json::to_value(&self.employment_info.title).unwrap());
+        input.insert("employer_years".to_string(), serde_json::to_value(self.employment_info.years_at_employer).unwrap());
+        input.insert("profession_years".to_string(), serde_json::to_value(self.employment_info.years_in_profession).unwrap());
+        input.insert("industry".to_string(), serde_json::to_value(&self.employment_info.industry).unwrap());
+        
+        // Additional attributes never override the standard fields
+        for (key, value) in &self.additional_attributes {
+            input.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        
+        input
+    }
+    
+    /// Calculates the applicant's age in whole years as of today
+    pub fn calculate_age(&self) -> Result<u32, ValidationError> {
+        self.calculate_age_at(Utc::now().date_naive())
+    }
+    
+    /// Calculates the applicant's age in whole years as of the given date.
+    ///
+    /// The date of birth must be ISO-8601 (`YYYY-MM-DD`). A Feb 29 birthday is
+    /// reached on Mar 1 in non-leap years. Future dates of birth are rejected.
+    pub fn calculate_age_at(&self, today: NaiveDate) -> Result<u32, ValidationError> {
+        let dob = NaiveDate::parse_from_str(self.personal_info.date_of_birth.trim(), "%Y-%m-%d")
+            .map_err(|_| validation_error("invalid_date_of_birth", format!("Invalid date of birth: '{}'", self.personal_info.date_of_birth)))?;
+        
+        if dob > today {
+            return Err(validation_error("future_date_of_birth", format!("Date of birth is in the future: {}", dob)));
+        }
+        
+        let mut age = today.year() - dob.year();
+        if (today.month(), today.day()) < (dob.month(), dob.day()) {
+            age -= 1;
+        }
+        
+        Ok(age as u32)
+    }
+    
+    /// Returns warnings about applicant data that could not be converted to model input
+    pub fn input_warnings(&self) -> Vec<String> {
+        self.input_warnings_at(Utc::now().date_naive())
+    }
+    
+    /// Returns warnings about applicant data that could not be converted to model input as of the given date
+    pub fn input_warnings_at(&self, today: NaiveDate) -> Vec<String> {
+        let mut warnings = Vec::new();
+        
+        if let Err(e) = self.calculate_age_at(today) {
+            warnings.push(format!("Age omitted from model input: {}", e.code));
+        }
+        
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn credit_info(credit_score: u16, credit_score_scale: Option<CreditScoreScale>) -> CreditInfo {
+        CreditInfo {
+            credit_score,
+            credit_score_scale,
+            open_accounts: 3,
+            delinquent_accounts: 0,
+            inquiries_last_6_months: 1,
+            oldest_account_age_months: 120,
+            total_credit_limit: 10000.0,
+            total_current_balance: 2500.0,
+            credit_utilization: 0.25,
+            public_records: 0,
+            collections: 0,
+        }
+    }
+    
+    fn applicant(date_of_birth: &str) -> ApplicantData {
+        ApplicantData {
+            applicant_id: "applicant-1".to_string(),
+            personal_info: PersonalInfo {
+                name: "Test Applicant".to_string(),
+                date_of_birth: date_of_birth.to_string(),
+                address: Address {
+                    street: "1 Main St".to_string(),
+                    city: "Springfield".to_string(),
+                    state: "IL".to_string(),
+                    postal_code: "62701".to_string(),
+                    country: "US".to_string(),
+                },
+                contact: ContactInfo {
+                    email: "applicant@example.com".to_string(),
+                    phone: "555-0100".to_string(),
+                },
+                years_at_address: 2.0,
+                dependents: 0,
+            },
+            financial_info: FinancialInfo {
+                annual_income: 60000.0,
+                monthly_housing_payment: 1200.0,
+                monthly_debt_payments: 300.0,
+                total_assets: 20000.0,
+                liquid_assets: 5000.0,
+                monthly_free_cash_flow: 1000.0,
+                debt_to_income_ratio: 0.3,
+            },
+            credit_info: credit_info(700, None),
+            employment_info: EmploymentInfo {
+                status: EmploymentStatus::Employed,
+                employer: "Acme".to_string(),
+                title: "Engineer".to_string(),
+                years_at_employer: 3.0,
+                years_in_profession: 5.0,
+                industry: "Manufacturing".to_string(),
+            },
+            additional_attributes: HashMap::new(),
+        }
+    }
+    
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+    
+    #[test]
+    fn calculate_age_at_handles_leap_day_birthdays() {
+        let applicant = applicant("2000-02-29");
+        
+        assert_eq!(applicant.calculate_age_at(date(2021, 2, 28)).unwrap(), 20);
+        assert_eq!(applicant.calculate_age_at(date(2021, 3, 1)).unwrap(), 21);
+        assert_eq!(applicant.calculate_age_at(date(2024, 2, 29)).unwrap(), 24);
+    }
+    
+    #[test]
+    fn calculate_age_at_rejects_future_and_invalid_dates() {
+        let error = applicant("2030-01-01").calculate_age_at(date(2026, 10, 14)).unwrap_err();
+        assert_eq!(error.code, "future_date_of_birth");
+        
+        let error = applicant("01/02/1990").calculate_age_at(date(2026, 10, 14)).unwrap_err();
+        assert_eq!(error.code, "invalid_date_of_birth");
+    }
+    
+    #[test]
+    fn normalize_credit_score_converts_between_fico_and_internal() {
+        let conversion = credit_info(575, None).normalize_credit_score(CreditScoreScale::Internal, true).unwrap();
+        assert_eq!(conversion.from, CreditScoreScale::Fico);
+        assert_eq!(conversion.converted, 500.0);
+        assert!(!conversion.out_of_range);
+        
+        let conversion = credit_info(1000, Some(CreditScoreScale::Internal))
+            .normalize_credit_score(CreditScoreScale::Fico, true)
+            .unwrap();
+        assert_eq!(conversion.converted, 850.0);
+    }
+    
+    #[test]
+    fn normalize_credit_score_clamps_and_flags_out_of_range_scores() {
+        let conversion = credit_info(900, None).normalize_credit_score(CreditScoreScale::Internal, false).unwrap();
+        assert_eq!(conversion.original, 900.0);
+        assert_eq!(conversion.converted, 1000.0);
+        assert!(conversion.out_of_range);
+    }
+    
+    #[test]
+    fn normalize_credit_score_rejects_out_of_range_scores_when_strict() {
+        let error = credit_info(200, None).normalize_credit_score(CreditScoreScale::Internal, true).unwrap_err();
+        assert_eq!(error.code, "credit_score_out_of_range");
+    }
+}
\ No newline at end of file
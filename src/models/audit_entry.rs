@@ -0,0 +1,84 @@
+//! Audit trail structures for models and assessments.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::risk_model::ModelStatus;
+
+/// Kind of mutation recorded in the audit trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+    Create,
+    StatusChange,
+    SoftDelete,
+    Restore,
+}
+
+/// Kind of entity an audit entry refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditEntityType {
+    Model,
+    Assessment,
+}
+
+/// Audit trail entry for a mutation of a model or assessment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Unique identifier for this entry
+    pub entry_id: String,
+    
+    /// Kind of entity mutated
+    pub entity_type: AuditEntityType,
+    
+    /// Identifier of the entity mutated
+    pub entity_id: String,
+    
+    /// Mutation performed
+    pub action: AuditAction,
+    
+    /// Who performed the mutation
+    pub actor: String,
+    
+    /// When the mutation was performed
+    pub timestamp: DateTime<Utc>,
+    
+    /// Model status before the mutation
+    pub before_status: Option<ModelStatus>,
+    
+    /// Model status after the mutation
+    pub after_status: Option<ModelStatus>,
+}
+
+impl AuditEntry {
+    /// Creates a new audit entry with a generated UUID, timestamped now
+    pub fn new(entity_type: AuditEntityType, entity_id: String, action: AuditAction, actor: String) -> Self {
+        Self::new_at(entity_type, entity_id, action, actor, Utc::now())
+    }
+    
+    /// Creates a new audit entry with a generated UUID and the given timestamp
+    pub fn new_at(
+        entity_type: AuditEntityType,
+        entity_id: String,
+        action: AuditAction,
+        actor: String,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            entry_id: uuid::Uuid::new_v4().to_string(),
+            entity_type,
+            entity_id,
+            action,
+            actor,
+            timestamp,
+            before_status: None,
+            after_status: None,
+        }
+    }
+    
+    /// Records the model status before and after the mutation
+    pub fn with_status_change(mut self, before: Option<ModelStatus>, after: Option<ModelStatus>) -> Self {
+        self.before_status = before;
+        self.after_status = after;
+        self
+    }
+}
\ No newline at end of file
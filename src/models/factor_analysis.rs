@@ -1,9 +1,30 @@
 //! Factor analysis and model explainability models.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use super::risk_assessment::Factor;
+use super::cmp_descending_nan_last;
+use super::risk_assessment::{Factor, RiskAssessment};
+use super::risk_model::RiskModel;
+
+/// Factors that an applicant cannot change, excluded from suggested actions
+const IMMUTABLE_FACTORS: &[&str] = &["age", "dependents", "name", "date_of_birth"];
+
+/// Estimated difficulty (1-5) of changing a factor
+fn action_difficulty(factor_name: &str) -> u8 {
+    let name = factor_name.to_lowercase();
+    if ["credit_score", "account_age", "public_records", "collections"].iter().any(|n| name.contains(n)) {
+        5
+    } else if ["income", "assets"].iter().any(|n| name.contains(n)) {
+        4
+    } else if ["utilization", "balance", "debt", "dti"].iter().any(|n| name.contains(n)) {
+        2
+    } else {
+        3
+    }
+}
 
 /// Factor analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +49,67 @@ pub struct FactorAnalysis {
     
     /// Analysis of continuous features
     pub continuous_factors: HashMap<String, ContinuousFactorAnalysis>,
+    
+    /// Additional metadata (e.g. the explanation method used)
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// Strategy used to attribute a score to individual factors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExplanationMethod {
+    /// Coefficient x value attribution for linear models
+    Linear,
+    
+    /// Generic model-agnostic attribution by perturbing inputs
+    Perturbation,
+    
+    /// Decision-path attribution for tree models
+    TreePath,
+    
+    /// Explanation returned by a remote model backend
+    Remote,
+}
+
+impl ExplanationMethod {
+    /// Returns the string representation of the method
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExplanationMethod::Linear => "linear",
+            ExplanationMethod::Perturbation => "perturbation",
+            ExplanationMethod::TreePath => "tree_path",
+            ExplanationMethod::Remote => "remote",
+        }
+    }
+    
+    /// Parses a method from its string representation
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "linear" => Some(ExplanationMethod::Linear),
+            "perturbation" => Some(ExplanationMethod::Perturbation),
+            "tree_path" => Some(ExplanationMethod::TreePath),
+            "remote" => Some(ExplanationMethod::Remote),
+            _ => None,
+        }
+    }
+    
+    /// Returns the method suited to a model type, if one of the type's tokens
+    /// (split on non-alphanumeric characters) names a known model family
+    pub fn for_model_type(model_type: &str) -> Option<Self> {
+        let model_type = model_type.to_lowercase();
+        let tokens = model_type.split(|c: char| !c.is_alphanumeric()).collect::<Vec<_>>();
+        let has_token = |names: &[&str]| tokens.iter().any(|t| names.contains(t));
+        
+        if has_token(&["linear", "logistic", "scorecard"]) {
+            Some(ExplanationMethod::Linear)
+        } else if has_token(&["tree", "forest", "gbm", "xgboost", "boost", "lightgbm"]) {
+            Some(ExplanationMethod::TreePath)
+        } else if has_token(&["remote"]) {
+            Some(ExplanationMethod::Remote)
+        } else {
+            None
+        }
+    }
 }
 
 /// Analysis of a categorical factor
@@ -62,19 +144,105 @@ pub struct ContinuousFactorAnalysis {
     pub sensitivity: Vec<(f64, f64)>,
 }
 
+impl ContinuousFactorAnalysis {
+    /// Returns the first sensitivity point where the curve changes direction,
+    /// ignoring points with a non-finite value or impact
+    pub fn inflection_point(&self) -> Option<(f64, f64)> {
+        let points: Vec<(f64, f64)> = self.sensitivity.iter()
+            .filter(|(value, impact)| value.is_finite() && impact.is_finite())
+            .cloned()
+            .collect();
+        let mut previous_direction = 0.0;
+        
+        for window in points.windows(2) {
+            if window[1].1 == window[0].1 {
+                continue;
+            }
+            
+            let direction = (window[1].1 - window[0].1).signum();
+            if previous_direction != 0.0 && direction != previous_direction {
+                return Some(window[0]);
+            }
+            previous_direction = direction;
+        }
+        
+        None
+    }
+}
+
 /// Human-readable explanations of factor analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Explanations {
     /// Overall explanation of the risk assessment
     pub overall_explanation: String,
     
-    /// Explanations for individual factors
+    /// Explanations for factors driving the score
     pub factor_explanations: Vec<FactorExplanation>,
     
+    /// Explanations for factors affecting confidence rather than the score
+    #[serde(default)]
+    pub confidence_explanations: Vec<FactorExplanation>,
+    
     /// Suggested actions to improve score
     pub suggested_actions: Vec<SuggestedAction>,
 }
 
+/// Assessment joined with its factor analysis and explanations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessmentExplanation {
+    /// The explained assessment
+    pub assessment: RiskAssessment,
+    
+    /// Factor analysis for the assessment
+    pub factor_analysis: FactorAnalysis,
+    
+    /// Overall explanation of the risk assessment
+    pub overall_explanation: String,
+    
+    /// Factor explanations ranked by importance
+    pub factor_explanations: Vec<FactorExplanation>,
+    
+    /// Explanations for factors affecting confidence
+    #[serde(default)]
+    pub confidence_explanations: Vec<FactorExplanation>,
+    
+    /// Suggested actions to improve score
+    pub suggested_actions: Vec<SuggestedAction>,
+    
+    /// True if the assessment has expired and should be re-run
+    pub expired: bool,
+}
+
+impl AssessmentExplanation {
+    /// Joins an assessment with its analysis and explanations
+    pub fn new(assessment: RiskAssessment, factor_analysis: FactorAnalysis, explanations: Explanations) -> Self {
+        Self::new_at(assessment, factor_analysis, explanations, Utc::now())
+    }
+    
+    /// Joins an assessment with its analysis and explanations, checking expiry as of `now`
+    pub fn new_at(
+        assessment: RiskAssessment,
+        factor_analysis: FactorAnalysis,
+        explanations: Explanations,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut factor_explanations = explanations.factor_explanations;
+        factor_explanations.sort_by(|a, b| {
+            cmp_descending_nan_last(a.importance, b.importance).then_with(|| a.factor_name.cmp(&b.factor_name))
+        });
+        
+        Self {
+            expired: assessment.is_expired_at(now),
+            assessment,
+            factor_analysis,
+            overall_explanation: explanations.overall_explanation,
+            factor_explanations,
+            confidence_explanations: explanations.confidence_explanations,
+            suggested_actions: explanations.suggested_actions,
+        }
+    }
+}
+
 /// Explanation for an individual factor
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FactorExplanation {
@@ -167,13 +335,22 @@ impl FactorAnalysis {
             impact_values: HashMap::new(),
             categorical_factors: HashMap::new(),
             continuous_factors: HashMap::new(),
+            metadata: HashMap::new(),
         }
     }
     
+    /// Records the explanation method used to produce this analysis
+    pub fn set_explanation_method(&mut self, method: ExplanationMethod) {
+        self.metadata.insert(
+            "explanation_method".to_string(),
+            serde_json::Value::String(method.as_str().to_string()),
+        );
+    }
+    
     /// Returns the top N factors by absolute impact
     pub fn top_factors(&self, n: usize) -> Vec<&Factor> {
         let mut factors = self.factors.iter().collect::<Vec<_>>();
-        factors.sort_by(|a, b| b.impact.abs().partial_cmp(&a.impact.abs()).unwrap());
+        factors.sort_by(|a, b| a.cmp_by_impact(b));
         factors.truncate(n);
         factors
     }
@@ -197,4 +374,213 @@ impl FactorAnalysis {
         
         importance
     }
+    
+    /// Returns the factors driving the score and those affecting confidence.
+    /// Features affecting both appear in both lists.
+    pub fn factors_by_effect(&self, model: &RiskModel) -> (Vec<&Factor>, Vec<&Factor>) {
+        let mut score_factors = Vec::new();
+        let mut confidence_factors = Vec::new();
+        
+        for factor in &self.factors {
+            let effect = model.feature_effect(&factor.name);
+            if effect.affects_score() {
+                score_factors.push(factor);
+            }
+            if effect.affects_confidence() {
+                confidence_factors.push(factor);
+            }
+        }
+        
+        (score_factors, confidence_factors)
+    }
+    
+    /// Generates suggested actions that each reduce the risk impact by at least `target_improvement`.
+    ///
+    /// Continuous factors use the closest point on their sensitivity curve, other
+    /// than the current value, that reaches the target; categorical factors use the
+    /// alternative value with the smallest sufficient improvement, with ties going
+    /// to the first value by name. Immutable factors are skipped.
+    pub fn suggest_actions(&self, target_improvement: f64) -> Vec<SuggestedAction> {
+        let mut actions = Vec::new();
+        
+        for (name, factor) in &self.continuous_factors {
+            if IMMUTABLE_FACTORS.contains(&name.as_str()) {
+                continue;
+            }
+            
+            let best = factor.sensitivity.iter()
+                .filter(|(value, impact)| *value != factor.value && factor.impact - impact >= target_improvement)
+                .min_by(|a, b| (a.0 - factor.value).abs().total_cmp(&(b.0 - factor.value).abs()));
+            
+            if let Some((value, impact)) = best {
+                actions.push(SuggestedAction {
+                    description: format!("Change {} from {:.2} to {:.2}", name, factor.value, value),
+                    estimated_impact: factor.impact - impact,
+                    difficulty: action_difficulty(name),
+                    related_factors: vec![name.clone()],
+                });
+            }
+        }
+        
+        for (name, factor) in &self.categorical_factors {
+            if IMMUTABLE_FACTORS.contains(&name.as_str()) {
+                continue;
+            }
+            
+            let best = factor.value_impacts.iter()
+                .filter(|(value, impact)| **value != factor.value && factor.impact - **impact >= target_improvement)
+                .max_by(|a, b| a.1.total_cmp(b.1).then_with(|| b.0.cmp(a.0)));
+            
+            if let Some((value, impact)) = best {
+                actions.push(SuggestedAction {
+                    description: format!("Change {} from {} to {}", name, factor.value, value),
+                    estimated_impact: factor.impact - impact,
+                    difficulty: action_difficulty(name),
+                    related_factors: vec![name.clone()],
+                });
+            }
+        }
+        
+        // Easiest actions first, then by largest improvement
+        actions.sort_by(|a, b| {
+            a.difficulty.cmp(&b.difficulty)
+                .then_with(|| b.estimated_impact.partial_cmp(&a.estimated_impact).unwrap_or(Ordering::Equal))
+                .then_with(|| a.description.cmp(&b.description))
+        });
+        actions
+    }
+    
+    /// Builds chart data for the top `n` factors, the score's position in the
+    /// model's score range and each continuous factor's sensitivity curve
+    pub fn visualization(&self, score: f64, score_range: (f64, f64), n: usize) -> Visualization {
+        let impact_points = self.top_factors(n).iter()
+            .map(|f| DataPoint {
+                x: serde_json::Value::String(f.name.clone()),
+                y: f.impact,
+            })
+            .collect();
+        
+        let (min, max) = score_range;
+        let position = if max > min { ((score - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 };
+        
+        let mut sensitivity_charts = HashMap::new();
+        for (name, factor) in &self.continuous_factors {
+            let points = factor.sensitivity.iter()
+                .map(|(x, y)| DataPoint { x: serde_json::json!(x), y: *y })
+                .collect();
+            sensitivity_charts.insert(name.clone(), ChartData {
+                chart_type: "line".to_string(),
+                title: format!("Sensitivity of {}", name),
+                x_label: name.clone(),
+                y_label: "Impact".to_string(),
+                series: vec![DataSeries { name: name.clone(), data: points }],
+            });
+        }
+        
+        Visualization {
+            factor_impact_chart: ChartData {
+                chart_type: "bar".to_string(),
+                title: "Factor Impact".to_string(),
+                x_label: "Factor".to_string(),
+                y_label: "Impact".to_string(),
+                series: vec![DataSeries { name: "Impact".to_string(), data: impact_points }],
+            },
+            score_distribution_chart: ChartData {
+                chart_type: "range".to_string(),
+                title: "Score Position".to_string(),
+                x_label: "Score".to_string(),
+                y_label: "Position in Range".to_string(),
+                series: vec![
+                    DataSeries {
+                        name: "Score Range".to_string(),
+                        data: vec![
+                            DataPoint { x: serde_json::json!(min), y: 0.0 },
+                            DataPoint { x: serde_json::json!(max), y: 1.0 },
+                        ],
+                    },
+                    DataSeries {
+                        name: "Applicant".to_string(),
+                        data: vec![DataPoint { x: serde_json::json!(score), y: position }],
+                    },
+                ],
+            },
+            sensitivity_charts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::risk_assessment::test_factor;
+    use super::super::risk_model::{test_feature, test_model, FeatureEffect, FeatureType};
+    
+    #[test]
+    fn for_model_type_matches_whole_tokens() {
+        assert_eq!(ExplanationMethod::for_model_type("logistic_regression"), Some(ExplanationMethod::Linear));
+        assert_eq!(ExplanationMethod::for_model_type("Scorecard"), Some(ExplanationMethod::Linear));
+        assert_eq!(ExplanationMethod::for_model_type("random-forest"), Some(ExplanationMethod::TreePath));
+        assert_eq!(ExplanationMethod::for_model_type("xgboost"), Some(ExplanationMethod::TreePath));
+        assert_eq!(ExplanationMethod::for_model_type("remote_engine"), Some(ExplanationMethod::Remote));
+        assert_eq!(ExplanationMethod::for_model_type("nonlinear_nn"), None);
+        assert_eq!(ExplanationMethod::for_model_type("creditcard"), None);
+    }
+    
+    #[test]
+    fn factors_by_effect_separates_confidence_only_features() {
+        let mut model = test_model();
+        let mut recency = test_feature("bureau_data_age_days", FeatureType::Numeric);
+        recency.effect = Some(FeatureEffect::Confidence);
+        let mut utilization = test_feature("credit_utilization", FeatureType::Numeric);
+        utilization.effect = Some(FeatureEffect::Both);
+        model.features.push(recency);
+        model.features.push(utilization);
+        
+        let mut analysis = FactorAnalysis::new("assessment-1".to_string());
+        analysis.factors = vec![
+            test_factor("credit_score", 0.4),
+            test_factor("bureau_data_age_days", 0.1),
+            test_factor("credit_utilization", 0.3),
+        ];
+        
+        let (score_factors, confidence_factors) = analysis.factors_by_effect(&model);
+        let names = |factors: &[&Factor]| factors.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&score_factors), vec!["credit_score", "credit_utilization"]);
+        assert_eq!(names(&confidence_factors), vec!["bureau_data_age_days", "credit_utilization"]);
+    }
+    
+    #[test]
+    fn suggest_actions_breaks_categorical_ties_by_value_name() {
+        let mut analysis = FactorAnalysis::new("assessment-1".to_string());
+        analysis.categorical_factors.insert("housing_status".to_string(), CategoricalFactorAnalysis {
+            name: "housing_status".to_string(),
+            value: "rent".to_string(),
+            impact: 0.5,
+            value_impacts: HashMap::from([
+                ("rent".to_string(), 0.5),
+                ("rent_to_own".to_string(), 0.2),
+                ("own".to_string(), 0.2),
+                ("mortgage".to_string(), 0.2),
+            ]),
+        });
+        
+        let actions = analysis.suggest_actions(0.1);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].description, "Change housing_status from rent to mortgage");
+    }
+    
+    #[test]
+    fn suggest_actions_skips_the_current_continuous_value() {
+        let mut analysis = FactorAnalysis::new("assessment-1".to_string());
+        analysis.continuous_factors.insert("credit_utilization".to_string(), ContinuousFactorAnalysis {
+            name: "credit_utilization".to_string(),
+            value: 0.3,
+            impact: 0.4,
+            sensitivity: vec![(0.1, 0.2), (0.3, 0.4), (0.5, 0.6)],
+        });
+        
+        let actions = analysis.suggest_actions(0.0);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].description, "Change credit_utilization from 0.30 to 0.10");
+    }
 }
\ No newline at end of file
@@ -8,11 +8,36 @@ mod risk_assessment;
 mod factor_analysis;
 mod model_output;
 mod applicant_data;
+mod score_distribution;
+mod reason_code;
+mod parameter_schema;
+mod audit_entry;
 mod request_context;
 
-pub use risk_model::{RiskModel, ModelStatus, FeatureDefinition, FeatureType, OutputDefinition};
-pub use risk_assessment::{RiskAssessment, Factor, ImpactDirection};
-pub use factor_analysis::{FactorAnalysis, Explanations, Visualization};
-pub use model_output::{ModelOutput, RiskTier};
-pub use applicant_data::ApplicantData;
-pub use request_context::RequestContext;
\ No newline at end of file
+pub use risk_model::{RiskModel, ModelStatus, FeatureDefinition, FeatureType, OutputDefinition, FeatureCoverage, AuditVerbosity, FeatureEffect, FeatureViolation, ViolationRule, InputReconciliation, CategoryAliases};
+pub use risk_assessment::{RiskAssessment, Factor, ImpactDirection, ModelSelection, SelectionStrategy, AssessmentDiff, FactorChange};
+pub use factor_analysis::{FactorAnalysis, Explanations, Visualization, ExplanationMethod, AssessmentExplanation};
+pub use model_output::{ModelOutput, RiskTier, ScoreBounds, ConfidenceBreakdown, ConfidenceComponent};
+pub use applicant_data::{ApplicantData, CreditScoreScale, CreditScoreConversion};
+pub use score_distribution::ScoreDistribution;
+pub use reason_code::{ReasonCode, ReasonCodeEntry, ReasonCodeMapping};
+pub use parameter_schema::{ParameterSchema, ParameterSpec, ParameterType};
+pub use audit_entry::{AuditEntry, AuditAction, AuditEntityType};
+pub use request_context::RequestContext;
+
+/// Builds a validation error with a static code and a formatted message
+pub(crate) fn validation_error(code: &'static str, message: String) -> validator::ValidationError {
+    let mut error = validator::ValidationError::new(code);
+    error.message = Some(message.into());
+    error
+}
+
+/// Orders values descending, with NaN values last
+pub(crate) fn cmp_descending_nan_last(a: f64, b: f64) -> std::cmp::Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, false) => std::cmp::Ordering::Greater,
+        (false, true) => std::cmp::Ordering::Less,
+        (true, true) => std::cmp::Ordering::Equal,
+        (false, false) => b.total_cmp(&a),
+    }
+}
\ No newline at end of file
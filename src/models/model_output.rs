@@ -2,6 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use validator::ValidationError;
+
+use super::risk_model::{InputReconciliation, RiskModel};
+use super::validation_error;
 
 /// Risk tier enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -25,8 +29,38 @@ pub enum RiskTier {
 impl RiskTier {
     /// Converts a risk score to a tier
     pub fn from_score(score: f64, max_score: f64) -> Self {
-        let normalized = score / max_score;
+        Self::from_normalized(score / max_score)
+    }
+    
+    /// Converts a risk score to a tier using the model's thresholds and score range.
+    ///
+    /// Each threshold is the minimum score for its tier. Thresholds are sorted
+    /// before use, and scores below the lowest cutoff fall into the lowest
+    /// threshold's tier, so the thresholds need not span the full range. When
+    /// the model has no usable thresholds the score is normalized over the
+    /// model's score range and the default cutoffs apply. A NaN score is treated
+    /// as the top of the range, so it falls into the highest-risk tier either way.
+    pub fn from_model(score: f64, model: &RiskModel) -> Self {
+        let score = if score.is_nan() { f64::INFINITY } else { score };
+        if let Some(thresholds) = model.sorted_tier_thresholds() {
+            let label = thresholds.iter()
+                .rev()
+                .find(|(cutoff, _)| score >= *cutoff)
+                .unwrap_or(&thresholds[0])
+                .1
+                .as_str();
+            
+            if let Some(tier) = RiskTier::from_label(label) {
+                return tier;
+            }
+        }
         
+        let (min, max) = model.score_range;
+        Self::from_normalized((score - min) / (max - min))
+    }
+    
+    /// Converts a score normalized to 0.0-1.0 to a tier
+    fn from_normalized(normalized: f64) -> Self {
         match normalized {
             n if n < 0.2 => RiskTier::VeryLow,
             n if n < 0.4 => RiskTier::Low,
@@ -46,6 +80,164 @@ impl RiskTier {
             RiskTier::VeryHigh => "Very High",
         }
     }
+    
+    /// Parses a tier from its string representation
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "Very Low" => Some(RiskTier::VeryLow),
+            "Low" => Some(RiskTier::Low),
+            "Moderate" => Some(RiskTier::Moderate),
+            "High" => Some(RiskTier::High),
+            "Very High" => Some(RiskTier::VeryHigh),
+            _ => None,
+        }
+    }
+    
+    /// Returns the normalized score range covered by the tier under the default cutoffs
+    fn normalized_bounds(&self) -> (f64, f64) {
+        match self {
+            RiskTier::VeryLow => (f64::NEG_INFINITY, 0.2),
+            RiskTier::Low => (0.2, 0.4),
+            RiskTier::Moderate => (0.4, 0.6),
+            RiskTier::High => (0.6, 0.8),
+            RiskTier::VeryHigh => (0.8, f64::INFINITY),
+        }
+    }
+    
+    /// Returns the score range covered by the tier under the model's thresholds,
+    /// or under the default cutoffs scaled to the model's score range.
+    /// Returns None if the model's thresholds do not use the tier.
+    pub fn model_bounds(&self, model: &RiskModel) -> Option<(f64, f64)> {
+        if let Some(thresholds) = model.sorted_tier_thresholds() {
+            if thresholds.iter().all(|(_, label)| RiskTier::from_label(label).is_some()) {
+                let index = thresholds.iter().position(|(_, label)| label == self.as_str())?;
+                let low = if index == 0 { f64::NEG_INFINITY } else { thresholds[index].0 };
+                let high = thresholds.get(index + 1).map_or(f64::INFINITY, |(cutoff, _)| *cutoff);
+                return Some((low, high));
+            }
+        }
+        
+        let (min, max) = model.score_range;
+        let (low, high) = self.normalized_bounds();
+        Some((min + low * (max - min), min + high * (max - min)))
+    }
+    
+    /// Converts a risk score to a tier using the model, retaining the previous tier
+    /// while the score is within `band` of the previous tier's bounds. The band is a
+    /// fraction of the model's score range.
+    pub fn from_model_with_hysteresis(score: f64, model: &RiskModel, previous: RiskTier, band: f64) -> Self {
+        let tier = RiskTier::from_model(score, model);
+        if tier == previous {
+            return tier;
+        }
+        
+        let (low, high) = match previous.model_bounds(model) {
+            Some(bounds) => bounds,
+            None => return tier,
+        };
+        let (min, max) = model.score_range;
+        let margin = band * (max - min);
+        if score >= low - margin && score < high + margin {
+            previous
+        } else {
+            tier
+        }
+    }
+}
+
+/// Policy score floor and ceiling applied to a segment
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBounds {
+    /// Minimum allowed score
+    pub floor: Option<f64>,
+    
+    /// Maximum allowed score
+    pub ceiling: Option<f64>,
+}
+
+impl ScoreBounds {
+    /// Validates the bounds against a model's score range
+    pub fn validate(&self, score_range: (f64, f64)) -> Result<(), ValidationError> {
+        let (min, max) = score_range;
+        
+        for bound in [self.floor, self.ceiling].iter().flatten() {
+            if bound.is_nan() || *bound < min || *bound > max {
+                return Err(validation_error("score_bound_out_of_range", format!("Score bound {} is outside the model score range", bound)));
+            }
+        }
+        
+        if let (Some(floor), Some(ceiling)) = (self.floor, self.ceiling) {
+            if floor > ceiling {
+                return Err(ValidationError::new("Score floor must not exceed ceiling"));
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Clamps a score to the bounds
+    pub fn clamp(&self, score: f64) -> f64 {
+        let mut clamped = score;
+        if let Some(floor) = self.floor {
+            clamped = clamped.max(floor);
+        }
+        if let Some(ceiling) = self.ceiling {
+            clamped = clamped.min(ceiling);
+        }
+        clamped
+    }
+}
+
+/// Contribution of a single factor to the final confidence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceComponent {
+    /// Component name (e.g. "imputation_penalty")
+    pub name: String,
+    
+    /// Signed contribution to confidence (penalties are negative)
+    pub contribution: f64,
+}
+
+/// Decomposition of the final confidence into its contributing factors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceBreakdown {
+    /// Confidence reported by the model before adjustments
+    pub base_confidence: f64,
+    
+    /// Adjustments applied on top of the base confidence
+    pub components: Vec<ConfidenceComponent>,
+}
+
+impl ConfidenceBreakdown {
+    /// Creates a breakdown starting from the model's base confidence
+    pub fn new(base_confidence: f64) -> Self {
+        Self {
+            base_confidence,
+            components: Vec::new(),
+        }
+    }
+    
+    /// Adds a signed contribution
+    pub fn add_component(&mut self, name: &str, contribution: f64) {
+        self.components.push(ConfidenceComponent {
+            name: name.to_string(),
+            contribution,
+        });
+    }
+    
+    /// Returns the final confidence, always equal to base plus all contributions
+    pub fn final_confidence(&self) -> f64 {
+        self.base_confidence + self.components.iter().map(|c| c.contribution).sum::<f64>()
+    }
+    
+    /// Adds a bounding component if needed so the final confidence stays within 0.0-1.0
+    pub fn bound(&mut self) {
+        let total = self.final_confidence();
+        let bounded = total.clamp(0.0, 1.0);
+        if bounded != total {
+            self.add_component("bounds", bounded - total);
+        }
+    }
 }
 
 /// Model execution output
@@ -68,6 +260,12 @@ pub struct ModelOutput {
     
     /// Warning messages
     pub warnings: Vec<String>,
+    
+    /// Breakdown of how the confidence was derived
+    pub confidence_breakdown: Option<ConfidenceBreakdown>,
+    
+    /// Uncertainty band around the score, for engines that can produce one
+    pub score_interval: Option<(f64, f64)>,
 }
 
 impl ModelOutput {
@@ -86,7 +284,37 @@ impl ModelOutput {
             raw_outputs,
             execution_time: 0.0,
             warnings: Vec::new(),
+            confidence_breakdown: None,
+            score_interval: None,
+        }
+    }
+    
+    /// Creates a new model output with the tier derived from the model's thresholds
+    pub fn for_model(
+        score: f64,
+        confidence: f64,
+        raw_outputs: HashMap<String, serde_json::Value>,
+        model: &RiskModel,
+    ) -> Self {
+        let mut output = Self::new(score, confidence, raw_outputs);
+        output.tier = RiskTier::from_model(score, model).as_str().to_string();
+        output
+    }
+    
+    /// Sets the score interval, warning if it straddles one of the model's tier boundaries.
+    /// Endpoints given in reverse order are swapped.
+    pub fn with_interval(mut self, interval: (f64, f64), model: &RiskModel) -> Self {
+        let (low, high) = if interval.0 > interval.1 { (interval.1, interval.0) } else { interval };
+        let tiers = (RiskTier::from_model(low, model), RiskTier::from_model(high, model));
+        if tiers.0 != tiers.1 {
+            self.add_warning(format!(
+                "Score interval [{:.1}, {:.1}] spans tiers {} to {}; applicant is near a tier boundary",
+                low, high, tiers.0.as_str(), tiers.1.as_str()
+            ));
         }
+        
+        self.score_interval = Some((low, high));
+        self
     }
     
     /// Adds a warning message
@@ -103,4 +331,211 @@ impl ModelOutput {
     pub fn has_warnings(&self) -> bool {
         !self.warnings.is_empty()
     }
+    
+    /// Applies policy score bounds, re-deriving the tier and recording any clamp.
+    /// Returns true if the score was clamped.
+    pub fn apply_score_bounds(&mut self, bounds: &ScoreBounds, model: &RiskModel) -> bool {
+        let clamped = bounds.clamp(self.score);
+        if clamped == self.score {
+            return false;
+        }
+        
+        self.add_warning(format!("Score {} clamped to {} by segment policy", self.score, clamped));
+        self.raw_outputs.insert(
+            "score_clamp".to_string(),
+            serde_json::json!({ "original_score": self.score, "clamped_score": clamped }),
+        );
+        self.score = clamped;
+        self.tier = RiskTier::from_model(clamped, model).as_str().to_string();
+        true
+    }
+    
+    /// Sets the confidence from a breakdown, keeping the breakdown for explanation
+    pub fn set_confidence_breakdown(&mut self, mut breakdown: ConfidenceBreakdown) {
+        breakdown.bound();
+        self.confidence = breakdown.final_confidence();
+        self.confidence_breakdown = Some(breakdown);
+    }
+    
+    /// Retains the previous tier for borderline scores, recording when hysteresis applied.
+    /// Returns true if the previous tier was retained.
+    pub fn apply_tier_hysteresis(&mut self, previous: RiskTier, band: f64, model: &RiskModel) -> bool {
+        let raw_tier = RiskTier::from_model(self.score, model);
+        let tier = RiskTier::from_model_with_hysteresis(self.score, model, previous, band);
+        if tier == raw_tier {
+            return false;
+        }
+        
+        self.tier = tier.as_str().to_string();
+        self.raw_outputs.insert(
+            "tier_hysteresis".to_string(),
+            serde_json::json!({ "raw_tier": raw_tier.as_str(), "retained_tier": tier.as_str(), "band": band }),
+        );
+        true
+    }
+    
+    /// Records what the model saw: reconciliation warnings and the applied default count
+    pub fn record_reconciliation(&mut self, reconciliation: &InputReconciliation) {
+        for warning in reconciliation.warnings() {
+            self.add_warning(warning);
+        }
+        self.raw_outputs.insert(
+            "_defaults_applied".to_string(),
+            serde_json::json!(reconciliation.applied_defaults.len()),
+        );
+    }
+    
+    /// Multiplies the confidence by a penalty factor, recording it in the breakdown and warnings
+    pub fn apply_confidence_penalty(&mut self, name: &str, factor: f64, reason: &str) {
+        let penalized = self.confidence * factor;
+        let mut breakdown = self.confidence_breakdown.take()
+            .unwrap_or_else(|| ConfidenceBreakdown::new(self.confidence));
+        breakdown.add_component(name, penalized - self.confidence);
+        self.set_confidence_breakdown(breakdown);
+        self.add_warning(reason.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::risk_model::test_model;
+    
+    fn model(score_range: (f64, f64), tier_thresholds: Option<Vec<(f64, &str)>>) -> RiskModel {
+        let mut model = test_model();
+        model.score_range = score_range;
+        model.tier_thresholds = tier_thresholds
+            .map(|t| t.into_iter().map(|(cutoff, label)| (cutoff, label.to_string())).collect());
+        model
+    }
+    
+    #[test]
+    fn from_model_sorts_unsorted_thresholds() {
+        let model = model((0.0, 100.0), Some(vec![(70.0, "High"), (0.0, "Low"), (40.0, "Moderate")]));
+        
+        assert_eq!(RiskTier::from_model(10.0, &model), RiskTier::Low);
+        assert_eq!(RiskTier::from_model(40.0, &model), RiskTier::Moderate);
+        assert_eq!(RiskTier::from_model(69.9, &model), RiskTier::Moderate);
+        assert_eq!(RiskTier::from_model(95.0, &model), RiskTier::High);
+    }
+    
+    #[test]
+    fn from_model_handles_thresholds_not_spanning_range() {
+        let model = model((300.0, 850.0), Some(vec![(600.0, "Moderate"), (750.0, "Very High"), (700.0, "High")]));
+        
+        // Scores below the lowest cutoff fall into the lowest threshold's tier
+        assert_eq!(RiskTier::from_model(300.0, &model), RiskTier::Moderate);
+        assert_eq!(RiskTier::from_model(650.0, &model), RiskTier::Moderate);
+        assert_eq!(RiskTier::from_model(720.0, &model), RiskTier::High);
+        assert_eq!(RiskTier::from_model(800.0, &model), RiskTier::VeryHigh);
+    }
+    
+    #[test]
+    fn from_model_normalizes_over_score_range() {
+        let model = model((300.0, 850.0), None);
+        
+        assert_eq!(RiskTier::from_model(300.0, &model), RiskTier::VeryLow);
+        assert_eq!(RiskTier::from_model(575.0, &model), RiskTier::Moderate);
+        assert_eq!(RiskTier::from_model(850.0, &model), RiskTier::VeryHigh);
+    }
+    
+    #[test]
+    fn from_model_puts_nan_scores_in_the_highest_tier() {
+        let partial = model((0.0, 100.0), Some(vec![(0.0, "Low"), (40.0, "Moderate"), (70.0, "High")]));
+        assert_eq!(RiskTier::from_model(f64::NAN, &partial), RiskTier::High);
+        
+        let default = model((0.0, 100.0), None);
+        assert_eq!(RiskTier::from_model(f64::NAN, &default), RiskTier::VeryHigh);
+    }
+    
+    #[test]
+    fn hysteresis_retains_previous_tier_near_boundary() {
+        let model = model((0.0, 100.0), None);
+        let mut output = ModelOutput::for_model(41.0, 0.9, HashMap::new(), &model);
+        
+        assert!(output.apply_tier_hysteresis(RiskTier::Low, 0.02, &model));
+        assert_eq!(output.tier, RiskTier::Low.as_str());
+        
+        let mut output = ModelOutput::for_model(45.0, 0.9, HashMap::new(), &model);
+        assert!(!output.apply_tier_hysteresis(RiskTier::Low, 0.02, &model));
+        assert_eq!(output.tier, RiskTier::Moderate.as_str());
+    }
+    
+    #[test]
+    fn with_interval_swaps_reversed_endpoints() {
+        let model = model((0.0, 100.0), None);
+        let output = ModelOutput::for_model(50.0, 0.9, HashMap::new(), &model)
+            .with_interval((55.0, 45.0), &model);
+        
+        assert_eq!(output.score_interval, Some((45.0, 55.0)));
+        assert!(!output.has_warnings());
+        
+        let output = ModelOutput::for_model(50.0, 0.9, HashMap::new(), &model)
+            .with_interval((65.0, 35.0), &model);
+        assert_eq!(output.score_interval, Some((35.0, 65.0)));
+        assert!(output.has_warnings());
+    }
+    
+    #[test]
+    fn apply_score_bounds_clamps_and_rederives_tier() {
+        let model = model((0.0, 100.0), None);
+        let bounds = ScoreBounds { floor: Some(30.0), ceiling: Some(70.0) };
+        assert!(bounds.validate(model.score_range).is_ok());
+        
+        let mut output = ModelOutput::for_model(10.0, 0.9, HashMap::new(), &model);
+        assert!(output.apply_score_bounds(&bounds, &model));
+        assert_eq!(output.score, 30.0);
+        assert_eq!(output.tier, RiskTier::Low.as_str());
+        assert!(output.raw_outputs.contains_key("score_clamp"));
+        
+        let mut output = ModelOutput::for_model(95.0, 0.9, HashMap::new(), &model);
+        assert!(output.apply_score_bounds(&bounds, &model));
+        assert_eq!(output.score, 70.0);
+        assert_eq!(output.tier, RiskTier::High.as_str());
+        
+        let mut output = ModelOutput::for_model(50.0, 0.9, HashMap::new(), &model);
+        assert!(!output.apply_score_bounds(&bounds, &model));
+        assert!(!output.has_warnings());
+    }
+    
+    #[test]
+    fn score_bounds_validate_rejects_inverted_and_out_of_range_bounds() {
+        let inverted = ScoreBounds { floor: Some(70.0), ceiling: Some(30.0) };
+        assert!(inverted.validate((0.0, 100.0)).is_err());
+        
+        let out_of_range = ScoreBounds { floor: None, ceiling: Some(120.0) };
+        assert_eq!(out_of_range.validate((0.0, 100.0)).unwrap_err().code, "score_bound_out_of_range");
+        
+        let nan = ScoreBounds { floor: Some(f64::NAN), ceiling: None };
+        assert!(nan.validate((0.0, 100.0)).is_err());
+    }
+    
+    #[test]
+    fn confidence_breakdown_components_sum_to_final_confidence() {
+        let model = model((0.0, 100.0), None);
+        let mut output = ModelOutput::for_model(50.0, 0.9, HashMap::new(), &model);
+        output.apply_confidence_penalty("imputation_penalty", 0.5, "Imputed 2 features");
+        output.apply_confidence_penalty("stale_data_penalty", 0.8, "Bureau data is stale");
+        
+        let breakdown = output.confidence_breakdown.as_ref().unwrap();
+        let sum = breakdown.base_confidence + breakdown.components.iter().map(|c| c.contribution).sum::<f64>();
+        assert_eq!(breakdown.components.len(), 2);
+        assert!((sum - output.confidence).abs() < 1e-12);
+        assert!((output.confidence - 0.36).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn confidence_breakdown_bound_clamps_with_a_component() {
+        let mut breakdown = ConfidenceBreakdown::new(0.9);
+        breakdown.add_component("consistency_bonus", 0.3);
+        breakdown.bound();
+        
+        assert_eq!(breakdown.components.last().unwrap().name, "bounds");
+        assert!((breakdown.final_confidence() - 1.0).abs() < 1e-12);
+        
+        let mut breakdown = ConfidenceBreakdown::new(0.5);
+        breakdown.add_component("imputation_penalty", -0.2);
+        breakdown.bound();
+        assert_eq!(breakdown.components.len(), 1);
+    }
 }
\ No newline at end of file
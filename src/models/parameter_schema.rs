@@ -0,0 +1,103 @@
+//! Model parameter schema definitions.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use validator::ValidationError;
+
+use super::validation_error;
+
+/// Parameters read by the service for every model type (scale and verbosity values are checked by `RiskModel::validate_all`)
+const RESERVED_PARAMETERS: &[&str] = &["explainer", "credit_score_scale", "audit_verbosity"];
+
+/// Expected JSON type of a model parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParameterType {
+    Number,
+    Integer,
+    String,
+    Boolean,
+    Array,
+    Object,
+}
+
+/// Specification of a single model parameter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterSpec {
+    /// Parameter name
+    pub name: String,
+    
+    /// Expected type
+    pub param_type: ParameterType,
+    
+    /// Is this parameter required?
+    pub required: bool,
+    
+    /// Valid range for numeric parameters
+    pub range: Option<(f64, f64)>,
+}
+
+/// Parameter schema for a model type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterSchema {
+    /// Model type the schema applies to
+    pub model_type: String,
+    
+    /// Declared parameters
+    pub parameters: Vec<ParameterSpec>,
+}
+
+impl ParameterSpec {
+    /// Returns true if the value has the expected type
+    fn type_matches(&self, value: &serde_json::Value) -> bool {
+        match self.param_type {
+            ParameterType::Number => value.is_number(),
+            ParameterType::Integer => value.is_i64() || value.is_u64(),
+            ParameterType::String => value.is_string(),
+            ParameterType::Boolean => value.is_boolean(),
+            ParameterType::Array => value.is_array(),
+            ParameterType::Object => value.is_object(),
+        }
+    }
+}
+
+impl ParameterSchema {
+    /// Validates parameters against the schema, returning every problem found
+    pub fn validate(&self, parameters: &HashMap<String, serde_json::Value>) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        
+        for spec in &self.parameters {
+            match parameters.get(&spec.name) {
+                None if spec.required => {
+                    errors.push(validation_error("missing_parameter", format!("Missing required parameter: {}", spec.name)));
+                }
+                None => {}
+                Some(value) if !spec.type_matches(value) => {
+                    errors.push(validation_error("invalid_parameter_type", format!("Parameter {} must be of type {:?}", spec.name, spec.param_type)));
+                }
+                Some(value) => {
+                    if let (Some((min, max)), Some(number)) = (spec.range, value.as_f64()) {
+                        if number < min || number > max {
+                            errors.push(validation_error("parameter_out_of_range", format!("Parameter {} is outside the range {} to {}", spec.name, min, max)));
+                        }
+                    }
+                }
+            }
+        }
+        
+        // Unknown keys are usually typos that would otherwise be silently ignored
+        let mut unknown = parameters.keys()
+            .filter(|name| !RESERVED_PARAMETERS.contains(&name.as_str()))
+            .filter(|name| !self.parameters.iter().any(|p| &p.name == *name))
+            .collect::<Vec<_>>();
+        unknown.sort();
+        for name in unknown {
+            errors.push(validation_error("unknown_parameter", format!("Unknown parameter for model type {}: {}", self.model_type, name)));
+        }
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
\ No newline at end of file
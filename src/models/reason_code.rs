@@ -0,0 +1,87 @@
+//! Adverse-action reason codes.
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::risk_assessment::Factor;
+
+/// Reason code and its consumer-facing statement
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReasonCodeEntry {
+    /// Standardized reason code
+    pub code: String,
+    
+    /// Statement disclosed to the consumer
+    pub statement: String,
+}
+
+/// Configurable mapping from factors to reason codes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonCodeMapping {
+    /// Codes keyed by factor name
+    pub factors: HashMap<String, ReasonCodeEntry>,
+    
+    /// Codes keyed by factor category, used when the name has no mapping
+    pub categories: HashMap<String, ReasonCodeEntry>,
+    
+    /// Generic code used when neither the name nor the category is mapped
+    pub generic: ReasonCodeEntry,
+}
+
+/// Adverse-action reason code derived from a factor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonCode {
+    /// Standardized reason code
+    pub code: String,
+    
+    /// Statement disclosed to the consumer
+    pub statement: String,
+    
+    /// Factor the code was derived from
+    pub factor_name: String,
+    
+    /// Impact of the factor on the risk score
+    pub impact: f64,
+}
+
+impl ReasonCodeMapping {
+    /// Looks up the reason code for a factor, falling back to the generic code
+    pub fn lookup(&self, factor: &Factor) -> &ReasonCodeEntry {
+        if let Some(entry) = self.factors.get(&factor.name) {
+            return entry;
+        }
+        
+        if let Some(entry) = self.categories.get(&factor.category) {
+            return entry;
+        }
+        
+        warn!("No reason code mapping for factor '{}' (category '{}'), using generic code", factor.name, factor.category);
+        &self.generic
+    }
+    
+    /// Maps factors, in order, to reason codes, keeping only the first factor for each code
+    pub fn reason_codes(&self, factors: &[&Factor], max: usize) -> Vec<ReasonCode> {
+        let mut codes: Vec<ReasonCode> = Vec::new();
+        
+        for factor in factors {
+            if codes.len() >= max {
+                break;
+            }
+            
+            let entry = self.lookup(factor);
+            if codes.iter().any(|c| c.code == entry.code) {
+                continue;
+            }
+            
+            codes.push(ReasonCode {
+                code: entry.code.clone(),
+                statement: entry.statement.clone(),
+                factor_name: factor.name.clone(),
+                impact: factor.impact,
+            });
+        }
+        
+        codes
+    }
+}
\ No newline at end of file
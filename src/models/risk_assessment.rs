@@ -2,8 +2,15 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use super::cmp_descending_nan_last;
+use super::model_output::ConfidenceBreakdown;
+use super::reason_code::{ReasonCode, ReasonCodeMapping};
+use super::score_distribution::ScoreDistribution;
+use super::validation_error;
 
 /// Direction of factor impact on risk score
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,6 +47,120 @@ pub struct Factor {
     pub description: String,
 }
 
+impl Factor {
+    /// Orders factors by descending absolute impact, with NaN impacts last and ties broken by name
+    pub fn cmp_by_impact(&self, other: &Factor) -> Ordering {
+        cmp_descending_nan_last(self.impact.abs(), other.impact.abs())
+            .then_with(|| self.name.cmp(&other.name))
+    }
+}
+
+/// Change in a factor between two assessments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactorChange {
+    /// Factor name
+    pub name: String,
+    
+    /// Impact in the earlier assessment (absent if the factor was added)
+    pub from_impact: Option<f64>,
+    
+    /// Impact in the later assessment (absent if the factor was dropped)
+    pub to_impact: Option<f64>,
+    
+    /// Direction in the earlier assessment
+    pub from_direction: Option<ImpactDirection>,
+    
+    /// Direction in the later assessment
+    pub to_direction: Option<ImpactDirection>,
+}
+
+/// Differences between two assessments of the same applicant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessmentDiff {
+    /// Earlier assessment identifier
+    pub from_assessment_id: String,
+    
+    /// Later assessment identifier
+    pub to_assessment_id: String,
+    
+    /// Change in risk score
+    pub score_delta: f64,
+    
+    /// Tier of the earlier assessment
+    pub from_tier: String,
+    
+    /// Tier of the later assessment
+    pub to_tier: String,
+    
+    /// Change in confidence
+    pub confidence_delta: f64,
+    
+    /// Factors present in both assessments whose impact or direction changed
+    pub changed_factors: Vec<FactorChange>,
+    
+    /// Factors only in the later assessment
+    pub added_factors: Vec<FactorChange>,
+    
+    /// Factors only in the earlier assessment
+    pub removed_factors: Vec<FactorChange>,
+    
+    /// Warnings about the comparison (e.g. different models)
+    pub warnings: Vec<String>,
+}
+
+impl AssessmentDiff {
+    /// Returns true if the risk tier changed
+    pub fn tier_changed(&self) -> bool {
+        self.from_tier != self.to_tier
+    }
+}
+
+/// Strategy used to select the scoring model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SelectionStrategy {
+    /// Model requested explicitly by the caller
+    Explicit,
+    
+    /// Model chosen by product and segment
+    ProductSegment,
+    
+    /// Model chosen by a gradual traffic ramp
+    TrafficRamp,
+    
+    /// Model chosen by A/B assignment
+    AbTest,
+}
+
+/// Record of which model was selected for an assessment and why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelSelection {
+    /// Selected model identifier
+    pub model_id: String,
+    
+    /// Selected model version
+    pub version: String,
+    
+    /// Strategy used for the selection
+    pub strategy: SelectionStrategy,
+    
+    /// Deciding factor (e.g. "sticky hash bucket 37/100 -> challenger")
+    pub deciding_factor: String,
+}
+
+impl ModelSelection {
+    /// Returns a deterministic bucket in `0..buckets` for a sticky assignment key
+    pub fn sticky_bucket(key: &str, buckets: u32) -> u32 {
+        // FNV-1a, stable across processes and releases unlike the std hasher
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in key.as_bytes() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        
+        (hash % buckets.max(1) as u64) as u32
+    }
+}
+
 /// Risk assessment result
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct RiskAssessment {
@@ -59,6 +180,9 @@ pub struct RiskAssessment {
     #[validate(range(min = 0.0, max = 1000.0))]
     pub risk_score: f64,
     
+    /// Uncertainty band around the risk score
+    pub score_interval: Option<(f64, f64)>,
+    
     /// Risk tier (categorical)
     #[validate(length(min = 1, max = 20))]
     pub risk_tier: String,
@@ -78,6 +202,15 @@ pub struct RiskAssessment {
     
     /// Additional metadata
     pub metadata: HashMap<String, serde_json::Value>,
+    
+    /// How the confidence was derived (underwriter view only)
+    pub confidence_breakdown: Option<ConfidenceBreakdown>,
+    
+    /// Percentile rank (0.0-100.0) of the score among same-segment applicants
+    pub segment_percentile: Option<f64>,
+    
+    /// When the assessment was soft-deleted
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl RiskAssessment {
@@ -91,45 +224,256 @@ impl RiskAssessment {
         key_factors: Vec<Factor>,
         expires_days: i64,
     ) -> Self {
-        let now = Utc::now();
+        Self::new_at(applicant_id, model_id, risk_score, risk_tier, confidence, key_factors, expires_days, Utc::now())
+    }
+    
+    /// Creates a new risk assessment performed at the given time
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_at(
+        applicant_id: String,
+        model_id: String,
+        risk_score: f64,
+        risk_tier: String,
+        confidence: f64,
+        key_factors: Vec<Factor>,
+        expires_days: i64,
+        now: DateTime<Utc>,
+    ) -> Self {
         Self {
             assessment_id: uuid::Uuid::new_v4().to_string(),
             applicant_id,
             model_id,
             risk_score,
+            score_interval: None,
             risk_tier,
             confidence,
             key_factors,
             assessment_date: now,
             expires_date: now + chrono::Duration::days(expires_days),
             metadata: HashMap::new(),
+            confidence_breakdown: None,
+            segment_percentile: None,
+            deleted_at: None,
         }
     }
     
+    /// Returns true if the assessment has been soft-deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+    
     /// Returns true if the assessment has expired
     pub fn is_expired(&self) -> bool {
-        Utc::now() > self.expires_date
+        self.is_expired_at(Utc::now())
+    }
+    
+    /// Returns true if the assessment had expired at the given time (the expiry instant itself is not expired)
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_date
     }
     
     /// Returns the top N factors by absolute impact
     pub fn top_factors(&self, n: usize) -> Vec<&Factor> {
         let mut factors = self.key_factors.iter().collect::<Vec<_>>();
-        factors.sort_by(|a, b| b.impact.abs().partial_cmp(&a.impact.abs()).unwrap());
+        factors.sort_by(|a, b| a.cmp_by_impact(b));
         factors.truncate(n);
         factors
     }
     
-    /// Returns positive factors (those reducing risk)
+    /// Returns positive factors (those reducing risk), ordered by absolute impact
     pub fn positive_factors(&self) -> Vec<&Factor> {
-        self.key_factors.iter()
+        let mut factors = self.key_factors.iter()
             .filter(|f| f.direction == ImpactDirection::Positive)
-            .collect()
+            .collect::<Vec<_>>();
+        factors.sort_by(|a, b| a.cmp_by_impact(b));
+        factors
     }
     
-    /// Returns negative factors (those increasing risk)
+    /// Returns negative factors (those increasing risk), ordered by absolute impact
     pub fn negative_factors(&self) -> Vec<&Factor> {
-        self.key_factors.iter()
+        let mut factors = self.key_factors.iter()
             .filter(|f| f.direction == ImpactDirection::Negative)
-            .collect()
+            .collect::<Vec<_>>();
+        factors.sort_by(|a, b| a.cmp_by_impact(b));
+        factors
+    }
+    
+    /// Sets the segment percentile, noting in metadata when the segment has too little data
+    pub fn set_segment_percentile(&mut self, distribution: &ScoreDistribution) {
+        self.segment_percentile = distribution.percentile(self.risk_score);
+        if self.segment_percentile.is_none() {
+            self.metadata.insert(
+                "segment_percentile_note".to_string(),
+                serde_json::Value::String(format!("Insufficient data for segment {}", distribution.segment)),
+            );
+        }
+    }
+    
+    /// Records the model selection in the assessment metadata
+    pub fn set_model_selection(&mut self, selection: &ModelSelection) {
+        if let Ok(value) = serde_json::to_value(selection) {
+            self.metadata.insert("model_selection".to_string(), value);
+        }
+    }
+    
+    /// Returns adverse-action reason codes for the top negative factors by absolute impact
+    pub fn adverse_action_codes(&self, mapping: &ReasonCodeMapping, max: usize) -> Vec<ReasonCode> {
+        mapping.reason_codes(&self.negative_factors(), max)
+    }
+    
+    /// Compares this assessment with a later one of the same applicant
+    pub fn diff(&self, other: &RiskAssessment) -> Result<AssessmentDiff, ValidationError> {
+        if self.applicant_id != other.applicant_id {
+            return Err(validation_error("applicant_mismatch", format!(
+                "Cannot compare assessments of different applicants ({} and {})",
+                self.applicant_id, other.applicant_id
+            )));
+        }
+        
+        let mut warnings = Vec::new();
+        if self.model_id != other.model_id {
+            warnings.push(format!(
+                "Assessments used different models ({} and {}); the comparison is less meaningful",
+                self.model_id, other.model_id
+            ));
+        }
+        
+        let change = |name: &str, from: Option<&Factor>, to: Option<&Factor>| FactorChange {
+            name: name.to_string(),
+            from_impact: from.map(|f| f.impact),
+            to_impact: to.map(|f| f.impact),
+            from_direction: from.map(|f| f.direction),
+            to_direction: to.map(|f| f.direction),
+        };
+        
+        let mut changed_factors = Vec::new();
+        let mut removed_factors = Vec::new();
+        for from in &self.key_factors {
+            match other.key_factors.iter().find(|f| f.name == from.name) {
+                Some(to) if to.impact != from.impact || to.direction != from.direction => {
+                    changed_factors.push(change(&from.name, Some(from), Some(to)));
+                }
+                Some(_) => {}
+                None => removed_factors.push(change(&from.name, Some(from), None)),
+            }
+        }
+        
+        let added_factors = other.key_factors.iter()
+            .filter(|to| !self.key_factors.iter().any(|f| f.name == to.name))
+            .map(|to| change(&to.name, None, Some(to)))
+            .collect();
+        
+        Ok(AssessmentDiff {
+            from_assessment_id: self.assessment_id.clone(),
+            to_assessment_id: other.assessment_id.clone(),
+            score_delta: other.risk_score - self.risk_score,
+            from_tier: self.risk_tier.clone(),
+            to_tier: other.risk_tier.clone(),
+            confidence_delta: other.confidence - self.confidence,
+            changed_factors,
+            added_factors,
+            removed_factors,
+            warnings,
+        })
+    }
+    
+    /// Tags the assessment as degraded, recording the skipped data sources
+    pub fn mark_degraded(&mut self, skipped_sources: &[String]) {
+        self.metadata.insert("degraded".to_string(), serde_json::Value::Bool(true));
+        self.metadata.insert("skipped_data_sources".to_string(), serde_json::json!(skipped_sources));
+    }
+    
+    /// Returns true if the assessment was produced without all data sources
+    pub fn is_degraded(&self) -> bool {
+        self.metadata.get("degraded").and_then(|v| v.as_bool()).unwrap_or(false)
+    }
+}
+
+/// Builds a neutral factor with the given impact for tests
+#[cfg(test)]
+pub(crate) fn test_factor(name: &str, impact: f64) -> Factor {
+    Factor {
+        name: name.to_string(),
+        value: serde_json::Value::Null,
+        impact,
+        direction: ImpactDirection::Neutral,
+        category: "test".to_string(),
+        description: String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn assessment_at(now: DateTime<Utc>) -> RiskAssessment {
+        RiskAssessment::new_at(
+            "applicant-1".to_string(),
+            "model-1".to_string(),
+            420.0,
+            "Moderate".to_string(),
+            0.9,
+            Vec::new(),
+            30,
+            now,
+        )
+    }
+    
+    #[test]
+    fn cmp_by_impact_sorts_nan_last_and_breaks_ties_by_name() {
+        let mut factors = [
+            test_factor("nan_b", f64::NAN),
+            test_factor("small", 0.1),
+            test_factor("tie_b", -0.5),
+            test_factor("nan_a", f64::NAN),
+            test_factor("tie_a", 0.5),
+            test_factor("large", -0.9),
+        ];
+        factors.sort_by(Factor::cmp_by_impact);
+        
+        let names: Vec<&str> = factors.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["large", "tie_a", "tie_b", "small", "nan_a", "nan_b"]);
+    }
+    
+    #[test]
+    fn assessment_expires_after_its_validity_period() {
+        let now = DateTime::parse_from_rfc3339("2026-01-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        let assessment = assessment_at(now);
+        
+        let expires = now + chrono::Duration::days(30);
+        assert_eq!(assessment.expires_date, expires);
+        assert!(!assessment.is_expired_at(expires - chrono::Duration::seconds(1)));
+        assert!(!assessment.is_expired_at(expires));
+        assert!(assessment.is_expired_at(expires + chrono::Duration::seconds(1)));
+    }
+    
+    #[test]
+    fn sticky_bucket_is_stable_for_known_keys() {
+        // Pinned values: changing the hash would reassign every sticky applicant
+        assert_eq!(ModelSelection::sticky_bucket("", 100), 37);
+        assert_eq!(ModelSelection::sticky_bucket("applicant-1", 100), 51);
+        assert_eq!(ModelSelection::sticky_bucket("applicant-2", 100), 62);
+        assert_eq!(ModelSelection::sticky_bucket("applicant-1", 1000), 951);
+        assert_eq!(ModelSelection::sticky_bucket("applicant-1", 0), 0);
+    }
+    
+    #[test]
+    fn set_model_selection_records_selection_in_metadata() {
+        let mut assessment = assessment_at(Utc::now());
+        let bucket = ModelSelection::sticky_bucket(&assessment.applicant_id, 100);
+        let selection = ModelSelection {
+            model_id: "model-2".to_string(),
+            version: "2.0.0".to_string(),
+            strategy: SelectionStrategy::TrafficRamp,
+            deciding_factor: format!("sticky hash bucket {}/100 -> challenger", bucket),
+        };
+        assessment.set_model_selection(&selection);
+        
+        assert_eq!(assessment.metadata["model_selection"], serde_json::json!({
+            "model_id": "model-2",
+            "version": "2.0.0",
+            "strategy": "TrafficRamp",
+            "deciding_factor": "sticky hash bucket 51/100 -> challenger",
+        }));
     }
 }
\ No newline at end of file
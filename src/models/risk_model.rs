@@ -1,10 +1,18 @@
 //! Risk model definition and related structures.
 
 use chrono::{DateTime, Utc};
+use log::warn;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use validator::{Validate, ValidationError};
 
+use super::applicant_data::CreditScoreScale;
+use super::factor_analysis::ExplanationMethod;
+use super::model_output::RiskTier;
+use super::parameter_schema::ParameterSchema;
+use super::risk_assessment::Factor;
+use super::validation_error;
+
 /// Current status of a risk model in its lifecycle
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ModelStatus {
@@ -27,6 +35,61 @@ pub enum ModelStatus {
     Archived,
 }
 
+impl ModelStatus {
+    /// Returns true if the lifecycle allows moving from this status to `next`
+    pub fn can_transition_to(&self, next: ModelStatus) -> bool {
+        use ModelStatus::*;
+        
+        matches!(
+            (self, next),
+            (Development, Testing)
+                | (Development, Archived)
+                | (Testing, Development)
+                | (Testing, Active)
+                | (Testing, Challenger)
+                | (Testing, Archived)
+                | (Challenger, Active)
+                | (Challenger, Deprecated)
+                | (Active, Deprecated)
+                | (Deprecated, Archived)
+        )
+    }
+}
+
+/// Level of detail recorded in the scoring audit for a model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditVerbosity {
+    /// Decision summary only
+    Minimal,
+    
+    /// Standard audit record
+    Standard,
+    
+    /// Full input snapshot and intermediate values, stored separately
+    Detailed,
+}
+
+impl AuditVerbosity {
+    /// Returns the string representation of the verbosity
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditVerbosity::Minimal => "minimal",
+            AuditVerbosity::Standard => "standard",
+            AuditVerbosity::Detailed => "detailed",
+        }
+    }
+    
+    /// Parses a verbosity from its string representation
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "minimal" => Some(AuditVerbosity::Minimal),
+            "standard" => Some(AuditVerbosity::Standard),
+            "detailed" => Some(AuditVerbosity::Detailed),
+            _ => None,
+        }
+    }
+}
+
 /// Data type for model features
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FeatureType {
@@ -37,6 +100,31 @@ pub enum FeatureType {
     Text,
 }
 
+/// Dimension of the assessment a feature affects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeatureEffect {
+    /// Feature drives the risk score
+    Score,
+    
+    /// Feature affects confidence only (e.g. data recency)
+    Confidence,
+    
+    /// Feature affects both score and confidence
+    Both,
+}
+
+impl FeatureEffect {
+    /// Returns true if the feature affects the risk score
+    pub fn affects_score(&self) -> bool {
+        matches!(self, FeatureEffect::Score | FeatureEffect::Both)
+    }
+    
+    /// Returns true if the feature affects confidence
+    pub fn affects_confidence(&self) -> bool {
+        matches!(self, FeatureEffect::Confidence | FeatureEffect::Both)
+    }
+}
+
 /// Definition of a feature used by the risk model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureDefinition {
@@ -60,6 +148,159 @@ pub struct FeatureDefinition {
     
     /// Human-readable description
     pub description: String,
+    
+    /// Canonical taxonomy name, used to align features across models
+    pub canonical_name: Option<String>,
+    
+    /// Dimension the feature affects (score when absent)
+    pub effect: Option<FeatureEffect>,
+}
+
+/// Rule broken by an input feature value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViolationRule {
+    /// Required feature is missing and has no default
+    Missing,
+    
+    /// Value does not match the feature's data type
+    TypeMismatch,
+    
+    /// Numeric value is outside the feature's range
+    OutOfRange,
+    
+    /// Categorical value is not one of the valid values
+    InvalidValue,
+    
+    /// Feature is not declared by the model
+    UnknownFeature,
+}
+
+/// Field-level violation of a feature definition by an input value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureViolation {
+    /// Feature name
+    pub feature: String,
+    
+    /// Rule that was broken
+    pub rule: ViolationRule,
+    
+    /// Offending value, if one was supplied
+    pub value: Option<serde_json::Value>,
+}
+
+/// Normalizes a categorical value for matching: lowercase, with whitespace,
+/// '-' and '_' removed so "Self Employed" and "SelfEmployed" compare equal
+fn normalize_category(value: &str) -> String {
+    value.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Categorical value aliases (alias -> valid value) with alias keys normalized once
+#[derive(Debug, Clone, Default)]
+pub struct CategoryAliases {
+    /// Normalized target keyed by normalized alias
+    aliases: HashMap<String, String>,
+}
+
+impl CategoryAliases {
+    /// Normalizes the aliases, rejecting aliases that collide after normalization
+    /// but map to different values
+    pub fn new(aliases: &HashMap<String, String>) -> Result<Self, ValidationError> {
+        let mut sorted: Vec<(&String, &String)> = aliases.iter().collect();
+        sorted.sort();
+        
+        let mut normalized: HashMap<String, String> = HashMap::new();
+        for (alias, canonical) in sorted {
+            let key = normalize_category(alias);
+            let target = normalize_category(canonical);
+            match normalized.get(&key) {
+                Some(existing) if *existing != target => {
+                    return Err(validation_error("conflicting_category_alias", format!("Alias '{}' conflicts with another alias mapping to a different value", alias)));
+                }
+                Some(_) => {}
+                None => {
+                    normalized.insert(key, target);
+                }
+            }
+        }
+        
+        Ok(Self { aliases: normalized })
+    }
+    
+    /// Returns the normalized target for a normalized value, if it is an alias
+    fn resolve(&self, normalized: &str) -> Option<&str> {
+        self.aliases.get(normalized).map(String::as_str)
+    }
+}
+
+/// Maximum number of points in a sensitivity sweep, bounding re-scoring calls
+pub const MAX_SENSITIVITY_STEPS: usize = 50;
+
+impl FeatureDefinition {
+    /// Returns evenly spaced values to sweep for sensitivity analysis.
+    ///
+    /// Uses the feature's range, or a +/-50% window around the current value
+    /// when no range is declared. The step count is capped at
+    /// `MAX_SENSITIVITY_STEPS`.
+    pub fn sensitivity_points(&self, current: f64, steps: usize) -> Vec<f64> {
+        let (min, max) = self.range.unwrap_or_else(|| {
+            let half_width = if current == 0.0 { 1.0 } else { current.abs() * 0.5 };
+            (current - half_width, current + half_width)
+        });
+        
+        let steps = steps.clamp(2, MAX_SENSITIVITY_STEPS);
+        let step = (max - min) / (steps - 1) as f64;
+        (0..steps).map(|i| min + step * i as f64).collect()
+    }
+    
+    /// Matches a categorical value to one of the valid values, ignoring case,
+    /// whitespace and separators and resolving aliases (alias -> valid value)
+    pub fn canonicalize_value(&self, value: &str, aliases: &CategoryAliases) -> Option<String> {
+        let valid_values = self.valid_values.as_ref()?;
+        let normalized = normalize_category(value);
+        let target = aliases.resolve(&normalized).unwrap_or(normalized.as_str());
+        
+        valid_values.iter()
+            .find(|v| normalize_category(v) == target)
+            .cloned()
+    }
+    
+    /// Checks a supplied value against the feature's type, range and valid values
+    pub fn check_value(&self, value: &serde_json::Value) -> Option<FeatureViolation> {
+        let violation = |rule| Some(FeatureViolation {
+            feature: self.name.clone(),
+            rule,
+            value: Some(value.clone()),
+        });
+        
+        let type_matches = match self.data_type {
+            FeatureType::Numeric => value.is_number(),
+            FeatureType::Boolean => value.is_boolean(),
+            FeatureType::Categorical | FeatureType::Text => value.is_string(),
+            FeatureType::DateTime => value.as_str()
+                .map(|v| DateTime::parse_from_rfc3339(v).is_ok() || chrono::NaiveDate::parse_from_str(v, "%Y-%m-%d").is_ok())
+                .unwrap_or(false),
+        };
+        if !type_matches {
+            return violation(ViolationRule::TypeMismatch);
+        }
+        
+        if let (Some((min, max)), Some(number)) = (self.range, value.as_f64()) {
+            if number < min || number > max {
+                return violation(ViolationRule::OutOfRange);
+            }
+        }
+        
+        if let (Some(valid_values), Some(text)) = (&self.valid_values, value.as_str()) {
+            if self.data_type == FeatureType::Categorical && !valid_values.iter().any(|v| v == text) {
+                return violation(ViolationRule::InvalidValue);
+            }
+        }
+        
+        None
+    }
 }
 
 /// Definition of a model output
@@ -81,6 +322,59 @@ pub struct OutputDefinition {
     pub description: String,
 }
 
+/// Coverage of applicant input fields by a model's features
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureCoverage {
+    /// Input fields consumed by the model
+    pub consumed: Vec<String>,
+    
+    /// Input fields the model ignores
+    pub ignored: Vec<String>,
+    
+    /// Declared features with no corresponding input field
+    pub unmapped: Vec<String>,
+    
+    /// Unmapped features that are required
+    pub unmapped_required: Vec<String>,
+}
+
+/// Differences between an input map and a model's declared features
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InputReconciliation {
+    /// Required features missing from the input with no default
+    pub missing_required: Vec<String>,
+    
+    /// Input keys the model does not declare, dropped before execution
+    pub dropped_extra: Vec<String>,
+    
+    /// Features filled from their default value
+    pub applied_defaults: Vec<String>,
+}
+
+impl InputReconciliation {
+    /// Returns true if every required feature is available
+    pub fn is_complete(&self) -> bool {
+        self.missing_required.is_empty()
+    }
+    
+    /// Returns warning messages describing the reconciliation
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        
+        for name in &self.missing_required {
+            warnings.push(format!("Required feature '{}' is missing and has no default", name));
+        }
+        if !self.dropped_extra.is_empty() {
+            warnings.push(format!("Dropped undeclared input feature(s): {}", self.dropped_extra.join(", ")));
+        }
+        if !self.applied_defaults.is_empty() {
+            warnings.push(format!("Applied default value(s) for: {}", self.applied_defaults.join(", ")));
+        }
+        
+        warnings
+    }
+}
+
 /// Risk model definition
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct RiskModel {
@@ -134,9 +428,26 @@ pub struct RiskModel {
     /// Model owner/creator
     #[validate(length(min = 1, max = 100))]
     pub owner: String,
+    
+    /// Features approved for use in decisioning (all declared features when absent)
+    pub approved_features: Option<Vec<String>>,
+    
+    /// Tier cutoffs as (minimum score, tier label); default normalized cutoffs when absent
+    pub tier_thresholds: Option<Vec<(f64, String)>>,
+    
+    /// Number of days an assessment from this model stays valid
+    pub default_validity_days: Option<i64>,
+    
+    /// When the model was soft-deleted
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl RiskModel {
+    /// Returns true if the model has been soft-deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+    
     /// Validates that the model has all required features
     pub fn validate_features(&self) -> Result<(), ValidationError> {
         if self.features.is_empty() {
@@ -186,4 +497,715 @@ impl RiskModel {
         
         Ok(())
     }
+    
+    /// Returns the explanation method from the `explainer` parameter, then the model type
+    /// (defaults to perturbation, which works for any model)
+    pub fn explanation_method(&self) -> ExplanationMethod {
+        self.parameters.get("explainer")
+            .and_then(|v| v.as_str())
+            .and_then(ExplanationMethod::from_name)
+            .or_else(|| ExplanationMethod::for_model_type(&self.model_type))
+            .unwrap_or(ExplanationMethod::Perturbation)
+    }
+    
+    /// Validates that every approved feature is a declared model feature
+    pub fn validate_approved_features(&self) -> Result<(), ValidationError> {
+        if let Some(approved) = &self.approved_features {
+            for name in approved {
+                if !self.features.iter().any(|f| &f.name == name) {
+                    return Err(validation_error("unknown_approved_feature", format!("Approved feature is not a model feature: {}", name)));
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Returns true if the feature may be used by this model
+    pub fn is_feature_approved(&self, name: &str) -> bool {
+        match &self.approved_features {
+            Some(approved) => approved.iter().any(|f| f == name),
+            None => true,
+        }
+    }
+    
+    /// Removes features that are not approved from the input, returning the dropped names
+    pub fn filter_approved_features(&self, input: &mut HashMap<String, serde_json::Value>) -> Vec<String> {
+        if self.approved_features.is_none() {
+            return Vec::new();
+        }
+        
+        let mut dropped = input.keys()
+            .filter(|name| !self.is_feature_approved(name))
+            .cloned()
+            .collect::<Vec<_>>();
+        dropped.sort();
+        
+        for name in &dropped {
+            input.remove(name);
+        }
+        
+        if !dropped.is_empty() {
+            warn!("Dropped {} unapproved feature(s) for model {}: {}", dropped.len(), self.model_id, dropped.join(", "));
+        }
+        
+        dropped
+    }
+    
+    /// Returns the canonical name of a feature, if it is declared and mapped
+    pub fn canonical_feature_name(&self, name: &str) -> Option<&str> {
+        self.features.iter()
+            .find(|f| f.name == name)
+            .and_then(|f| f.canonical_name.as_deref())
+    }
+    
+    /// Splits factors into those keyed by canonical name and those without a mapping.
+    /// When several factors map to the same canonical name the first is kept and the
+    /// others are reported as unmapped.
+    pub fn canonicalize_factors<'a>(&self, factors: &'a [Factor]) -> (HashMap<String, &'a Factor>, Vec<&'a Factor>) {
+        let mut mapped: HashMap<String, &'a Factor> = HashMap::new();
+        let mut unmapped = Vec::new();
+        
+        for factor in factors {
+            match self.canonical_feature_name(&factor.name) {
+                Some(canonical) if mapped.contains_key(canonical) => {
+                    warn!(
+                        "Factors '{}' and '{}' of model {} both map to canonical factor '{}'",
+                        mapped[canonical].name, factor.name, self.model_id, canonical
+                    );
+                    unmapped.push(factor);
+                }
+                Some(canonical) => {
+                    mapped.insert(canonical.to_string(), factor);
+                }
+                None => unmapped.push(factor),
+            }
+        }
+        
+        (mapped, unmapped)
+    }
+    
+    /// Reports which input fields the model consumes or ignores, and which features lack a field
+    pub fn coverage(&self, input: &HashMap<String, serde_json::Value>) -> FeatureCoverage {
+        let mut consumed = Vec::new();
+        let mut ignored = Vec::new();
+        
+        for name in input.keys() {
+            let declared = self.features.iter().any(|f| &f.name == name);
+            if declared && self.is_feature_approved(name) {
+                consumed.push(name.clone());
+            } else {
+                ignored.push(name.clone());
+            }
+        }
+        
+        let missing = self.features.iter()
+            .filter(|f| !input.contains_key(&f.name))
+            .collect::<Vec<_>>();
+        let unmapped = missing.iter().map(|f| f.name.clone()).collect();
+        let unmapped_required = missing.iter()
+            .filter(|f| f.required)
+            .map(|f| f.name.clone())
+            .collect();
+        
+        consumed.sort();
+        ignored.sort();
+        
+        FeatureCoverage {
+            consumed,
+            ignored,
+            unmapped,
+            unmapped_required,
+        }
+    }
+    
+    /// Returns the credit score scale from the `credit_score_scale` parameter (defaults to FICO)
+    pub fn credit_score_scale(&self) -> CreditScoreScale {
+        self.parameters.get("credit_score_scale")
+            .and_then(|v| v.as_str())
+            .and_then(CreditScoreScale::from_name)
+            .unwrap_or(CreditScoreScale::Fico)
+    }
+    
+    /// Validates that the `credit_score_scale` parameter, if set, names a known scale
+    pub fn validate_credit_score_scale(&self) -> Result<(), ValidationError> {
+        match self.parameters.get("credit_score_scale") {
+            None => Ok(()),
+            Some(value) if value.as_str().and_then(CreditScoreScale::from_name).is_some() => Ok(()),
+            Some(value) => Err(validation_error("unknown_credit_score_scale", format!("Unknown credit score scale: {}", value))),
+        }
+    }
+    
+    /// Returns the audit verbosity from the `audit_verbosity` parameter (defaults to standard)
+    pub fn audit_verbosity(&self) -> AuditVerbosity {
+        self.parameters.get("audit_verbosity")
+            .and_then(|v| v.as_str())
+            .and_then(AuditVerbosity::from_name)
+            .unwrap_or(AuditVerbosity::Standard)
+    }
+    
+    /// Validates that the `audit_verbosity` parameter, if set, names a known level
+    pub fn validate_audit_verbosity(&self) -> Result<(), ValidationError> {
+        match self.parameters.get("audit_verbosity") {
+            None => Ok(()),
+            Some(value) if value.as_str().and_then(AuditVerbosity::from_name).is_some() => Ok(()),
+            Some(value) => Err(validation_error("unknown_audit_verbosity", format!("Unknown audit verbosity: {}", value))),
+        }
+    }
+    
+    /// Returns the effect of a feature, treating undeclared features as score-affecting
+    pub fn feature_effect(&self, name: &str) -> FeatureEffect {
+        self.features.iter()
+            .find(|f| f.name == name)
+            .and_then(|f| f.effect)
+            .unwrap_or(FeatureEffect::Score)
+    }
+    
+    /// Returns the tier thresholds sorted ascending by cutoff, ignoring NaN cutoffs
+    pub fn sorted_tier_thresholds(&self) -> Option<Vec<(f64, String)>> {
+        let mut thresholds = self.tier_thresholds.as_ref()?
+            .iter()
+            .filter(|(cutoff, _)| !cutoff.is_nan())
+            .cloned()
+            .collect::<Vec<_>>();
+        
+        if thresholds.is_empty() {
+            return None;
+        }
+        
+        thresholds.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        Some(thresholds)
+    }
+    
+    /// Validates that tier thresholds use known labels, lie within the score range and are unique
+    pub fn validate_tier_thresholds(&self) -> Result<(), ValidationError> {
+        let thresholds = match &self.tier_thresholds {
+            Some(thresholds) => thresholds,
+            None => return Ok(()),
+        };
+        
+        if thresholds.is_empty() {
+            return Err(ValidationError::new("Tier thresholds must not be empty when set"));
+        }
+        
+        let (min, max) = self.score_range;
+        let mut cutoffs = Vec::new();
+        for (cutoff, label) in thresholds {
+            if RiskTier::from_label(label).is_none() {
+                return Err(validation_error("unknown_tier_label", format!("Unknown tier label: {}", label)));
+            }
+            if cutoff.is_nan() || *cutoff < min || *cutoff > max {
+                return Err(validation_error("tier_cutoff_out_of_range", format!("Tier cutoff {} is outside the score range", cutoff)));
+            }
+            if cutoffs.contains(cutoff) {
+                return Err(validation_error("duplicate_tier_cutoff", format!("Duplicate tier cutoff: {}", cutoff)));
+            }
+            cutoffs.push(*cutoff);
+        }
+        
+        Ok(())
+    }
+    
+    /// Fills missing features that declare a default value, returning the filled names
+    pub fn apply_defaults(&self, input: &mut HashMap<String, serde_json::Value>) -> Vec<String> {
+        let mut applied = Vec::new();
+        
+        for feature in &self.features {
+            let missing = input.get(&feature.name).filter(|v| !v.is_null()).is_none();
+            if let (true, Some(default)) = (missing, &feature.default_value) {
+                input.insert(feature.name.clone(), default.clone());
+                applied.push(feature.name.clone());
+            }
+        }
+        
+        applied
+    }
+    
+    /// Validates an input map against the model's feature definitions.
+    /// Missing features with a default are accepted, as `apply_defaults` fills them.
+    pub fn validate_input(&self, input: &HashMap<String, serde_json::Value>) -> Result<(), Vec<FeatureViolation>> {
+        let mut violations = Vec::new();
+        
+        for feature in &self.features {
+            match input.get(&feature.name).filter(|v| !v.is_null()) {
+                Some(value) => {
+                    if let Some(violation) = feature.check_value(value) {
+                        violations.push(violation);
+                    }
+                }
+                None => {
+                    if feature.required && feature.default_value.is_none() {
+                        violations.push(FeatureViolation {
+                            feature: feature.name.clone(),
+                            rule: ViolationRule::Missing,
+                            value: None,
+                        });
+                    }
+                }
+            }
+        }
+        
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+    
+    /// Validates that the validation metrics meet the minimum thresholds required for promotion
+    pub fn validate_promotion_metrics(&self, required: &HashMap<String, f64>) -> Result<(), ValidationError> {
+        let mut names = required.keys().collect::<Vec<_>>();
+        names.sort();
+        
+        for name in names {
+            let minimum = required[name];
+            if !minimum.is_finite() {
+                return Err(validation_error("invalid_metric_threshold", format!("Minimum for validation metric {} is not a finite number", name)));
+            }
+            
+            match self.validation_metrics.get(name) {
+                None => return Err(validation_error("missing_validation_metric", format!("Missing validation metric: {}", name))),
+                Some(value) if !value.is_finite() => {
+                    return Err(validation_error("invalid_validation_metric", format!("Validation metric {} is not a finite number", name)));
+                }
+                Some(value) if *value < minimum => {
+                    return Err(validation_error("validation_metric_below_minimum", format!("Validation metric {} is {} (minimum {})", name, value, minimum)));
+                }
+                Some(_) => {}
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates that the default validity is positive and does not exceed `max_days`
+    pub fn validate_validity_days(&self, max_days: i64) -> Result<(), ValidationError> {
+        match self.default_validity_days {
+            Some(days) if days <= 0 => Err(ValidationError::new("Default validity days must be positive")),
+            Some(days) if days > max_days => {
+                Err(validation_error("validity_days_exceeds_maximum", format!("Default validity days {} exceeds maximum of {}", days, max_days)))
+            }
+            _ => Ok(()),
+        }
+    }
+    
+    /// Resolves the assessment validity in days.
+    ///
+    /// An explicit override takes precedence over the model default, and the
+    /// result never exceeds `hard_cap_days`.
+    pub fn expires_days(&self, override_days: Option<i64>, hard_cap_days: i64) -> Result<i64, ValidationError> {
+        let days = override_days
+            .or(self.default_validity_days)
+            .ok_or_else(|| validation_error("missing_validity_days", format!("Model {} has no default validity days", self.model_id)))?;
+        
+        if days <= 0 {
+            return Err(ValidationError::new("Validity days must be positive"));
+        }
+        
+        Ok(days.min(hard_cap_days))
+    }
+    
+    /// Aligns an input map with the model's features: fills defaults, drops
+    /// undeclared keys and reports required features that are still missing
+    pub fn reconcile_input(&self, input: &mut HashMap<String, serde_json::Value>) -> InputReconciliation {
+        let applied_defaults = self.apply_defaults(input);
+        
+        let mut dropped_extra = input.keys()
+            .filter(|name| !self.features.iter().any(|f| &f.name == *name))
+            .cloned()
+            .collect::<Vec<_>>();
+        dropped_extra.sort();
+        for name in &dropped_extra {
+            input.remove(name);
+        }
+        
+        let missing_required = self.features.iter()
+            .filter(|f| f.required && input.get(&f.name).filter(|v| !v.is_null()).is_none())
+            .map(|f| f.name.clone())
+            .collect();
+        
+        InputReconciliation {
+            missing_required,
+            dropped_extra,
+            applied_defaults,
+        }
+    }
+    
+    /// Runs all model validations, including the parameter schema for the model type if one is given.
+    /// `max_validity_days` is the configured ceiling for the model's default validity.
+    pub fn validate_all(&self, schema: Option<&ParameterSchema>, max_validity_days: i64) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        
+        let checks = [
+            self.validate_features(),
+            self.validate_outputs(),
+            self.validate_score_range(),
+            self.validate_approved_features(),
+            self.validate_tier_thresholds(),
+            self.validate_credit_score_scale(),
+            self.validate_audit_verbosity(),
+            self.validate_validity_days(max_validity_days),
+        ];
+        errors.extend(checks.into_iter().filter_map(Result::err));
+        
+        if let Some(schema) = schema {
+            if let Err(schema_errors) = schema.validate(&self.parameters) {
+                errors.extend(schema_errors);
+            }
+        }
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+    
+    /// Validates what-if overrides: each key must be a declared feature and each value must satisfy it
+    pub fn validate_overrides(&self, overrides: &HashMap<String, serde_json::Value>) -> Result<(), Vec<FeatureViolation>> {
+        let mut names = overrides.keys().collect::<Vec<_>>();
+        names.sort();
+        
+        let mut violations = Vec::new();
+        for name in names {
+            let value = &overrides[name];
+            match self.features.iter().find(|f| &f.name == name) {
+                Some(feature) => violations.extend(feature.check_value(value)),
+                None => violations.push(FeatureViolation {
+                    feature: name.clone(),
+                    rule: ViolationRule::UnknownFeature,
+                    value: Some(value.clone()),
+                }),
+            }
+        }
+        
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+    
+    /// Canonicalizes categorical inputs to the features' valid values.
+    /// Returns a warning for each coerced value, or a violation for each value that matches nothing.
+    pub fn canonicalize_input(
+        &self,
+        input: &mut HashMap<String, serde_json::Value>,
+        aliases: &CategoryAliases,
+    ) -> Result<Vec<String>, Vec<FeatureViolation>> {
+        let mut warnings = Vec::new();
+        let mut violations = Vec::new();
+        
+        for feature in &self.features {
+            if feature.data_type != FeatureType::Categorical || feature.valid_values.is_none() {
+                continue;
+            }
+            
+            let value = match input.get(&feature.name).and_then(|v| v.as_str()) {
+                Some(value) => value.to_string(),
+                None => continue,
+            };
+            
+            match feature.canonicalize_value(&value, aliases) {
+                Some(canonical) if canonical == value => {}
+                Some(canonical) => {
+                    warnings.push(format!("Coerced {} value '{}' to '{}'", feature.name, value, canonical));
+                    input.insert(feature.name.clone(), serde_json::Value::String(canonical));
+                }
+                None => violations.push(FeatureViolation {
+                    feature: feature.name.clone(),
+                    rule: ViolationRule::InvalidValue,
+                    value: Some(serde_json::Value::String(value)),
+                }),
+            }
+        }
+        
+        if violations.is_empty() {
+            Ok(warnings)
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Builds a minimal valid active model over a 0-1000 score range for tests
+#[cfg(test)]
+pub(crate) fn test_model() -> RiskModel {
+    RiskModel {
+        model_id: "model-1".to_string(),
+        name: "Test model".to_string(),
+        version: "1.0.0".to_string(),
+        model_type: "creditcard".to_string(),
+        target_segment: "retail".to_string(),
+        parameters: HashMap::new(),
+        metadata: HashMap::new(),
+        created_date: Utc::now(),
+        modified_date: Utc::now(),
+        status: ModelStatus::Active,
+        score_range: (0.0, 1000.0),
+        features: vec![test_feature("credit_score", FeatureType::Numeric)],
+        outputs: vec![OutputDefinition {
+            name: "risk_score".to_string(),
+            data_type: FeatureType::Numeric,
+            range: Some((0.0, 1000.0)),
+            valid_values: None,
+            description: String::new(),
+        }],
+        validation_metrics: HashMap::new(),
+        owner: "risk-team".to_string(),
+        approved_features: None,
+        tier_thresholds: None,
+        default_validity_days: None,
+        deleted_at: None,
+    }
+}
+
+/// Builds an optional feature definition for tests
+#[cfg(test)]
+pub(crate) fn test_feature(name: &str, data_type: FeatureType) -> FeatureDefinition {
+    FeatureDefinition {
+        name: name.to_string(),
+        data_type,
+        required: false,
+        default_value: None,
+        range: None,
+        valid_values: None,
+        description: String::new(),
+        canonical_name: None,
+        effect: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::risk_assessment::test_factor;
+    
+    fn employment_model() -> RiskModel {
+        let mut feature = test_feature("employment_status", FeatureType::Categorical);
+        feature.required = true;
+        feature.valid_values = Some(vec!["Full Time".to_string(), "Self Employed".to_string()]);
+        
+        let mut model = test_model();
+        model.features = vec![feature];
+        model
+    }
+    
+    fn aliases(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(alias, canonical)| (alias.to_string(), canonical.to_string())).collect()
+    }
+    
+    #[test]
+    fn canonicalize_value_resolves_aliases_and_ignores_separators() {
+        let model = employment_model();
+        let feature = &model.features[0];
+        let aliases = CategoryAliases::new(&aliases(&[("SE", "self-employed")])).unwrap();
+        
+        assert_eq!(feature.canonicalize_value("  full_time ", &aliases), Some("Full Time".to_string()));
+        assert_eq!(feature.canonicalize_value("self-employed", &aliases), Some("Self Employed".to_string()));
+        assert_eq!(feature.canonicalize_value(" se", &aliases), Some("Self Employed".to_string()));
+        assert_eq!(feature.canonicalize_value("retired", &aliases), None);
+    }
+    
+    #[test]
+    fn category_aliases_reject_conflicting_collisions() {
+        assert!(CategoryAliases::new(&aliases(&[("self-emp", "Self Employed"), ("Self Emp", "Full Time")])).is_err());
+        assert!(CategoryAliases::new(&aliases(&[("self-emp", "Self Employed"), ("Self Emp", "self_employed")])).is_ok());
+    }
+    
+    #[test]
+    fn canonicalize_input_coerces_values_and_reports_unmatched() {
+        let model = employment_model();
+        let aliases = CategoryAliases::default();
+        
+        let mut input = HashMap::new();
+        input.insert("employment_status".to_string(), serde_json::json!("FULL-TIME"));
+        let warnings = model.canonicalize_input(&mut input, &aliases).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(input["employment_status"], serde_json::json!("Full Time"));
+        
+        input.insert("employment_status".to_string(), serde_json::json!("retired"));
+        let violations = model.canonicalize_input(&mut input, &aliases).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, ViolationRule::InvalidValue);
+        assert_eq!(input["employment_status"], serde_json::json!("retired"));
+    }
+    
+    #[test]
+    fn credit_score_scale_parses_names_and_rejects_unknown_values() {
+        let mut model = test_model();
+        assert_eq!(model.credit_score_scale(), CreditScoreScale::Fico);
+        assert!(model.validate_all(None, 365).is_ok());
+        
+        model.parameters.insert("credit_score_scale".to_string(), serde_json::json!("internal"));
+        assert_eq!(model.credit_score_scale(), CreditScoreScale::Internal);
+        assert!(model.validate_all(None, 365).is_ok());
+        
+        model.parameters.insert("credit_score_scale".to_string(), serde_json::json!("Internal"));
+        let errors = model.validate_all(None, 365).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "unknown_credit_score_scale");
+    }
+    
+    #[test]
+    fn audit_verbosity_is_applied_per_model() {
+        let routine = test_model();
+        let mut high_stakes = test_model();
+        high_stakes.parameters.insert("audit_verbosity".to_string(), serde_json::json!("detailed"));
+        let mut minimal = test_model();
+        minimal.parameters.insert("audit_verbosity".to_string(), serde_json::json!("minimal"));
+        
+        assert_eq!(routine.audit_verbosity(), AuditVerbosity::Standard);
+        assert_eq!(high_stakes.audit_verbosity(), AuditVerbosity::Detailed);
+        assert_eq!(minimal.audit_verbosity(), AuditVerbosity::Minimal);
+        assert!(high_stakes.validate_all(None, 365).is_ok());
+    }
+    
+    #[test]
+    fn audit_verbosity_rejects_unknown_values() {
+        let mut model = test_model();
+        model.parameters.insert("audit_verbosity".to_string(), serde_json::json!("Detailed"));
+        
+        let errors = model.validate_all(None, 365).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "unknown_audit_verbosity");
+    }
+    
+    #[test]
+    fn validate_promotion_metrics_rejects_non_finite_values() {
+        let mut model = test_model();
+        model.validation_metrics.insert("auc".to_string(), 0.8);
+        
+        let required = HashMap::from([("auc".to_string(), 0.75)]);
+        assert!(model.validate_promotion_metrics(&required).is_ok());
+        
+        let required_nan = HashMap::from([("auc".to_string(), f64::NAN)]);
+        assert_eq!(model.validate_promotion_metrics(&required_nan).unwrap_err().code, "invalid_metric_threshold");
+        
+        model.validation_metrics.insert("auc".to_string(), f64::NAN);
+        assert_eq!(model.validate_promotion_metrics(&required).unwrap_err().code, "invalid_validation_metric");
+    }
+    
+    #[test]
+    fn explanation_method_prefers_parameter_then_model_type() {
+        let mut model = test_model();
+        model.model_type = "logistic_regression".to_string();
+        assert_eq!(model.explanation_method(), ExplanationMethod::Linear);
+        
+        model.parameters.insert("explainer".to_string(), serde_json::json!("remote"));
+        assert_eq!(model.explanation_method(), ExplanationMethod::Remote);
+        
+        model.parameters.insert("explainer".to_string(), serde_json::json!("unknown"));
+        assert_eq!(model.explanation_method(), ExplanationMethod::Linear);
+        
+        model.model_type = "gbm".to_string();
+        assert_eq!(model.explanation_method(), ExplanationMethod::TreePath);
+    }
+    
+    #[test]
+    fn explanation_method_defaults_to_perturbation() {
+        let mut model = test_model();
+        assert_eq!(model.explanation_method(), ExplanationMethod::Perturbation);
+        
+        model.model_type = "nonlinear_nn".to_string();
+        assert_eq!(model.explanation_method(), ExplanationMethod::Perturbation);
+    }
+    
+    #[test]
+    fn filter_approved_features_drops_unapproved_inputs() {
+        let mut model = test_model();
+        model.features.push(test_feature("zip_code", FeatureType::Categorical));
+        model.approved_features = Some(vec!["credit_score".to_string()]);
+        assert!(model.validate_approved_features().is_ok());
+        
+        let mut input = HashMap::new();
+        input.insert("credit_score".to_string(), serde_json::json!(700));
+        input.insert("zip_code".to_string(), serde_json::json!("62701"));
+        input.insert("nickname".to_string(), serde_json::json!("Ace"));
+        
+        let dropped = model.filter_approved_features(&mut input);
+        assert_eq!(dropped, vec!["nickname".to_string(), "zip_code".to_string()]);
+        assert_eq!(input.keys().collect::<Vec<_>>(), vec!["credit_score"]);
+    }
+    
+    #[test]
+    fn validate_approved_features_rejects_undeclared_names() {
+        let mut model = test_model();
+        model.approved_features = Some(vec!["credit_score".to_string(), "zip_code".to_string()]);
+        
+        assert_eq!(model.validate_approved_features().unwrap_err().code, "unknown_approved_feature");
+    }
+    
+    fn model_with_canonical(features: &[(&str, &str)]) -> RiskModel {
+        let mut model = test_model();
+        model.features = features.iter()
+            .map(|(name, canonical)| {
+                let mut feature = test_feature(name, FeatureType::Numeric);
+                feature.canonical_name = Some(canonical.to_string());
+                feature
+            })
+            .collect();
+        model
+    }
+    
+    #[test]
+    fn canonicalize_factors_aligns_models_using_different_names() {
+        let card = model_with_canonical(&[("fico_score", "credit_score")]);
+        let mortgage = model_with_canonical(&[("bureau_score", "credit_score")]);
+        
+        let card_factors = [test_factor("fico_score", 0.4), test_factor("utilization", 0.2)];
+        let mortgage_factors = [test_factor("bureau_score", 0.3)];
+        let (card_mapped, card_unmapped) = card.canonicalize_factors(&card_factors);
+        let (mortgage_mapped, mortgage_unmapped) = mortgage.canonicalize_factors(&mortgage_factors);
+        
+        assert_eq!(card_mapped["credit_score"].name, "fico_score");
+        assert_eq!(mortgage_mapped["credit_score"].name, "bureau_score");
+        assert_eq!(card_unmapped.len(), 1);
+        assert_eq!(card_unmapped[0].name, "utilization");
+        assert!(mortgage_unmapped.is_empty());
+    }
+    
+    #[test]
+    fn canonicalize_factors_reports_colliding_factors_as_unmapped() {
+        let model = model_with_canonical(&[("fico_score", "credit_score"), ("bureau_score", "credit_score")]);
+        let factors = [test_factor("fico_score", 0.4), test_factor("bureau_score", 0.3)];
+        
+        let (mapped, unmapped) = model.canonicalize_factors(&factors);
+        assert_eq!(mapped.len(), 1);
+        assert_eq!(mapped["credit_score"].name, "fico_score");
+        assert_eq!(unmapped.len(), 1);
+        assert_eq!(unmapped[0].name, "bureau_score");
+    }
+    
+    #[test]
+    fn coverage_reports_unmapped_required_feature() {
+        let mut model = test_model();
+        let mut bureau_score = test_feature("bureau_score", FeatureType::Numeric);
+        bureau_score.required = true;
+        model.features.push(bureau_score);
+        model.features.push(test_feature("loyalty_tier", FeatureType::Categorical));
+        
+        let mut input = HashMap::new();
+        input.insert("credit_score".to_string(), serde_json::json!(700));
+        input.insert("annual_income".to_string(), serde_json::json!(60000.0));
+        
+        let coverage = model.coverage(&input);
+        assert_eq!(coverage.consumed, vec!["credit_score".to_string()]);
+        assert_eq!(coverage.ignored, vec!["annual_income".to_string()]);
+        assert_eq!(coverage.unmapped, vec!["bureau_score".to_string(), "loyalty_tier".to_string()]);
+        assert_eq!(coverage.unmapped_required, vec!["bureau_score".to_string()]);
+    }
+    
+    #[test]
+    fn validate_all_rejects_invalid_validity_days() {
+        let mut model = test_model();
+        model.default_validity_days = Some(30);
+        assert!(model.validate_all(None, 90).is_ok());
+        
+        model.default_validity_days = Some(-5);
+        assert_eq!(model.validate_all(None, 90).unwrap_err().len(), 1);
+        
+        model.default_validity_days = Some(120);
+        assert_eq!(model.validate_all(None, 90).unwrap_err()[0].code, "validity_days_exceeds_maximum");
+    }
 }
\ No newline at end of file
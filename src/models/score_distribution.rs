@@ -0,0 +1,135 @@
+//! Per-segment score distribution structures.
+
+use serde::{Deserialize, Serialize};
+
+/// Incrementally maintained score distribution for a customer segment.
+///
+/// Scores are accumulated into a fixed number of equal-width bins over the
+/// model's score range, so memory use does not grow with the number of
+/// applicants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreDistribution {
+    /// Segment identifier
+    pub segment: String,
+    
+    /// Score range covered by the bins
+    pub score_range: (f64, f64),
+    
+    /// Number of scores in each bin
+    pub bins: Vec<u64>,
+    
+    /// Total number of recorded scores
+    pub count: u64,
+    
+    /// Minimum number of scores before percentiles are reported
+    pub min_samples: u64,
+}
+
+impl ScoreDistribution {
+    /// Creates an empty distribution
+    pub fn new(segment: String, score_range: (f64, f64), bin_count: usize, min_samples: u64) -> Self {
+        Self {
+            segment,
+            score_range,
+            bins: vec![0; bin_count.max(1)],
+            count: 0,
+            min_samples,
+        }
+    }
+    
+    /// Returns the bin index for a score, clamping out-of-range scores to the edge bins.
+    /// Returns None if the distribution has no bins.
+    fn bin_index(&self, score: f64) -> Option<usize> {
+        if self.bins.is_empty() {
+            return None;
+        }
+        
+        let (min, max) = self.score_range;
+        let width = (max - min) / self.bins.len() as f64;
+        if width <= 0.0 || score <= min {
+            return Some(0);
+        }
+        
+        Some((((score - min) / width) as usize).min(self.bins.len() - 1))
+    }
+    
+    /// Records a score, ignoring NaN scores and distributions without bins
+    pub fn record(&mut self, score: f64) {
+        if score.is_nan() {
+            return;
+        }
+        
+        if let Some(index) = self.bin_index(score) {
+            self.bins[index] += 1;
+            self.count += 1;
+        }
+    }
+    
+    /// Returns true if enough scores have been recorded to report percentiles
+    pub fn has_sufficient_data(&self) -> bool {
+        self.count > 0 && self.count >= self.min_samples
+    }
+    
+    /// Returns the percentile rank (0.0-100.0) of a score within the segment
+    pub fn percentile(&self, score: f64) -> Option<f64> {
+        if !self.has_sufficient_data() || score.is_nan() {
+            return None;
+        }
+        
+        let index = self.bin_index(score)?;
+        let below: u64 = self.bins[..index].iter().sum();
+        
+        // Count half of the score's own bin as below it
+        let rank = below as f64 + self.bins[index] as f64 / 2.0;
+        Some(rank / self.count as f64 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn percentile_matches_known_distribution() {
+        let mut distribution = ScoreDistribution::new("retail".to_string(), (0.0, 100.0), 4, 8);
+        for score in [10.0, 20.0, 30.0, 40.0, 60.0, 70.0, 80.0, 90.0] {
+            distribution.record(score);
+        }
+        
+        // Two scores per bin; half of the score's own bin counts as below it
+        assert_eq!(distribution.percentile(10.0), Some(12.5));
+        assert_eq!(distribution.percentile(50.0), Some(62.5));
+        assert_eq!(distribution.percentile(99.0), Some(87.5));
+        // Out-of-range scores fall into the edge bins
+        assert_eq!(distribution.percentile(-10.0), Some(12.5));
+        assert_eq!(distribution.percentile(150.0), Some(87.5));
+        assert_eq!(distribution.percentile(f64::NAN), None);
+    }
+    
+    #[test]
+    fn percentile_is_omitted_until_min_samples() {
+        let mut distribution = ScoreDistribution::new("retail".to_string(), (0.0, 100.0), 10, 3);
+        distribution.record(50.0);
+        distribution.record(60.0);
+        assert_eq!(distribution.percentile(50.0), None);
+        
+        distribution.record(70.0);
+        assert!(distribution.percentile(50.0).is_some());
+    }
+    
+    #[test]
+    fn empty_bins_do_not_panic() {
+        let mut distribution: ScoreDistribution = serde_json::from_value(serde_json::json!({
+            "segment": "retail",
+            "score_range": [0.0, 100.0],
+            "bins": [],
+            "count": 5,
+            "min_samples": 1
+        }))
+        .unwrap();
+        
+        assert_eq!(distribution.percentile(50.0), None);
+        distribution.record(50.0);
+        assert_eq!(distribution.count, 5);
+    }
+}
\ No newline at end of file